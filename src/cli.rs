@@ -2,13 +2,19 @@ use clap::{Parser, Subcommand};
 use clap_complete::engine::ArgValueCandidates;
 use clap_complete::Shell;
 
-use crate::completions::worktree_names;
+use crate::completions::{tag_values, worktree_names};
 
 #[derive(Parser)]
 #[command(name = "worktree")]
 #[command(about = "Manage git worktrees with port allocation", long_about = None)]
 #[command(version)]
 pub struct Cli {
+    /// Print the resolved port, branch, terminal and shell settings and where each
+    /// value came from, then exit. Special paths, excludes, verbs, git identity and
+    /// submodule settings are not shown.
+    #[arg(long, global = true)]
+    pub print_config: bool,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -19,6 +25,27 @@ pub enum Commands {
     New {
         /// Parameter for the new worktree (e.g., branch name, issue ID)
         param: Option<String>,
+
+        /// Use this local branch, attaching to it if it already exists
+        #[arg(long, conflicts_with = "from")]
+        branch: Option<String>,
+
+        /// Base the worktree on an existing local or remote ref (e.g. origin/feature/x)
+        #[arg(long, conflicts_with = "branch")]
+        from: Option<String>,
+
+        /// SSH destination (e.g. user@host) the worktree runs on; terminals and
+        /// port allocation target this host instead of the local machine
+        #[arg(long)]
+        ssh_host: Option<String>,
+
+        /// SSH port to use with --ssh-host, if not the default 22
+        #[arg(long, requires = "ssh_host")]
+        ssh_port: Option<u16>,
+
+        /// Directory on the remote host, if not the worktree's local path
+        #[arg(long, requires = "ssh_host")]
+        remote_path: Option<std::path::PathBuf>,
     },
 
     /// Initialize worktree configuration for this project
@@ -30,10 +57,25 @@ pub enum Commands {
         /// Skip script generation
         #[arg(long)]
         no_scripts: bool,
+
+        /// Re-run setup for an already-initialized project, backing up the existing
+        /// settings files (other generated files are overwritten without a backup)
+        #[arg(long)]
+        reconfigure: bool,
     },
 
-    /// Execute the project's run script
-    Run,
+    /// Execute the project's run script, or a named custom verb
+    Run {
+        /// Custom verb to run (from settings `verbs`). Omit to run the run phase of
+        /// `.worktree/worktree.toml` when it has one, otherwise run.sh.
+        verb: Option<String>,
+
+        /// Start run.sh in the background and track it for cleanup on close,
+        /// instead of running it in the foreground. A manifest run phase always
+        /// runs in the foreground.
+        #[arg(short, long)]
+        background: bool,
+    },
 
     /// Execute the project's stop script
     Stop,
@@ -51,6 +93,10 @@ pub enum Commands {
         /// Interactively select worktree to close
         #[arg(short, long)]
         interactive: bool,
+
+        /// Keep the branch (and its unpushed commits) when removing the worktree
+        #[arg(long)]
+        keep_branch: bool,
     },
 
     /// Open an existing worktree in the configured terminal
@@ -62,6 +108,14 @@ pub enum Commands {
         /// Interactively select worktree to open
         #[arg(short, long)]
         interactive: bool,
+
+        /// Attach the tmux session read-only (observer mode)
+        #[arg(short, long)]
+        read_only: bool,
+
+        /// Detach any other clients already attached to the tmux session
+        #[arg(short, long)]
+        detach_others: bool,
     },
 
     /// Rename a worktree's display name
@@ -76,6 +130,10 @@ pub enum Commands {
         /// Clear custom name and revert to directory name
         #[arg(long)]
         clear: bool,
+
+        /// Allow a display name that duplicates another worktree in the project
+        #[arg(long)]
+        allow_duplicate: bool,
     },
 
     /// List active worktrees (current project by default)
@@ -87,6 +145,29 @@ pub enum Commands {
         /// Show worktrees from all projects
         #[arg(short, long)]
         all: bool,
+
+        /// Skip collecting git status for each worktree (faster on large sets)
+        #[arg(long)]
+        no_status: bool,
+
+        /// Only show worktrees carrying this tag
+        #[arg(long, add = ArgValueCandidates::new(tag_values))]
+        tag: Option<String>,
+    },
+
+    /// Add or remove tags on a worktree
+    Tag {
+        /// Worktree to tag (name or directory). If omitted, uses current worktree.
+        #[arg(add = ArgValueCandidates::new(worktree_names))]
+        name: Option<String>,
+
+        /// Tags to add
+        #[arg(long, value_name = "TAG")]
+        add: Vec<String>,
+
+        /// Tags to remove
+        #[arg(long, value_name = "TAG", add = ArgValueCandidates::new(tag_values))]
+        remove: Vec<String>,
     },
 
     /// Clean up inactive worktrees
@@ -109,6 +190,10 @@ pub enum Commands {
         /// Worktree name to show status for (optional, defaults to current worktree)
         #[arg(add = ArgValueCandidates::new(worktree_names))]
         name: Option<String>,
+
+        /// Output the full state and live git info as JSON
+        #[arg(long)]
+        json: bool,
     },
 
     /// Generate shell completion scripts
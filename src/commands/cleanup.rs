@@ -104,6 +104,10 @@ fn find_worktrees_with_activity() -> Result<Vec<WorktreeInfo>> {
 
     let now = Utc::now();
 
+    // Open one backend for the whole scan so an in-process implementation can
+    // reuse a single repository handle across every worktree.
+    let backend = git::select_backend();
+
     for entry in WalkDir::new(&base_dir)
         .min_depth(1)
         .max_depth(3)
@@ -112,7 +116,7 @@ fn find_worktrees_with_activity() -> Result<Vec<WorktreeInfo>> {
     {
         if entry.file_name() == "state.json" {
             if let Ok(state) = WorktreeState::load(entry.path()) {
-                let last_commit = git::get_latest_commit_date(&state.worktree_dir).ok();
+                let last_commit = backend.latest_commit_date(&state.worktree_dir).ok();
 
                 let days_inactive = if let Some(commit_date) = last_commit {
                     (now - commit_date).num_days()
@@ -8,7 +8,12 @@ use crate::config::{paths, state::WorktreeState};
 use crate::git;
 use crate::terminal;
 
-pub fn execute(name: Option<String>, force: bool, interactive: bool) -> Result<()> {
+pub fn execute(
+    name: Option<String>,
+    force: bool,
+    interactive: bool,
+    keep_branch: bool,
+) -> Result<()> {
     // Determine which worktree to close
     let worktree_state = resolve_worktree(name, interactive)?;
 
@@ -35,6 +40,46 @@ pub fn execute(name: Option<String>, force: bool, interactive: bool) -> Result<(
         worktree_state.worktree_dir.display()
     );
 
+    // Guard against silently destroying work: removal force-removes the git
+    // worktree, so warn and require an extra confirmation when the tree is
+    // dirty or has commits that aren't on the upstream. `--force` bypasses it.
+    if !force {
+        if let Ok(status) = git::get_worktree_status(&worktree_state.worktree_dir) {
+            let dirty = status.dirty > 0 || status.untracked > 0;
+            let unpushed = status.ahead > 0;
+            if dirty || unpushed {
+                println!(
+                    "\n  {} This worktree has {}.",
+                    "⚠".yellow(),
+                    describe_risk(&status)
+                );
+
+                // Unpushed commits are safe as long as the branch is kept, so
+                // `--keep-branch` lets that case through without nagging.
+                if unpushed && !dirty && keep_branch {
+                    println!(
+                        "  {} Branch {} kept; its commits are preserved.",
+                        "✓".green(),
+                        worktree_state.branch.cyan()
+                    );
+                } else {
+                    print!(
+                        "\n  {} ",
+                        "This work may be lost. Type 'yes' to remove anyway:".red()
+                    );
+                    io::stdout().flush()?;
+
+                    let mut input = String::new();
+                    io::stdin().read_line(&mut input)?;
+                    if input.trim().to_lowercase() != "yes" {
+                        println!("{}", "Cancelled.".dimmed());
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
+
     // Confirm unless force flag is set
     if !force {
         print!(
@@ -119,7 +164,7 @@ fn resolve_worktree(name: Option<String>, interactive: bool) -> Result<WorktreeS
         if worktrees.is_empty() {
             bail!("No worktrees found for this project.");
         }
-        return select_worktree(&worktrees);
+        return common::select_worktree(&worktrees, "close");
     }
 
     // If name provided, find by name (search current project first, then all)
@@ -136,7 +181,7 @@ fn resolve_worktree(name: Option<String>, interactive: bool) -> Result<WorktreeS
         }
         if matches.len() > 1 {
             println!("{}", "Multiple worktrees match that name:".yellow());
-            return select_worktree(&matches);
+            return common::select_worktree(&matches, "close");
         }
 
         // If not found in current project, search all worktrees
@@ -151,7 +196,7 @@ fn resolve_worktree(name: Option<String>, interactive: bool) -> Result<WorktreeS
             1 => return Ok(matches.into_iter().next().unwrap()),
             _ => {
                 println!("{}", "Multiple worktrees match that name:".yellow());
-                return select_worktree(&matches);
+                return common::select_worktree(&matches, "close");
             }
         }
     }
@@ -166,7 +211,7 @@ fn resolve_worktree(name: Option<String>, interactive: bool) -> Result<WorktreeS
     if worktrees.is_empty() {
         bail!("No worktrees found for this project.");
     }
-    select_worktree(&worktrees)
+    common::select_worktree(&worktrees, "close")
 }
 
 /// Try to get the current project name from the git repo or worktree state
@@ -223,54 +268,37 @@ fn find_all_worktrees() -> Result<Vec<WorktreeState>> {
     Ok(worktrees)
 }
 
-/// Interactive worktree selection
-fn select_worktree(worktrees: &[WorktreeState]) -> Result<WorktreeState> {
-    println!("\n{}", "Select worktree to close:".bold());
-
-    for (i, wt) in worktrees.iter().enumerate() {
-        let port_range = if wt.ports.is_empty() {
-            "no ports".to_string()
-        } else {
-            format!("{}-{}", wt.ports.first().unwrap(), wt.ports.last().unwrap())
-        };
-
-        // Show display name with directory if custom name is set
-        let name_display = if wt.has_custom_name() {
-            format!("{} - {}", wt.effective_name().green(), wt.name.dimmed())
-        } else {
-            wt.name.green().to_string()
-        };
-
-        println!(
-            "  {}) {}/{} {} {}",
-            (i + 1).to_string().cyan(),
-            wt.project_name.blue(),
-            name_display,
-            format!("(ports {})", port_range).dimmed(),
-            format!("[{}]", wt.branch).dimmed()
-        );
+/// Build a human summary of a worktree's at-risk work, e.g.
+/// `3 modified files, 1 untracked file, 2 unpushed commits`.
+fn describe_risk(status: &git::WorktreeStatus) -> String {
+    let mut parts = Vec::new();
+    if status.dirty > 0 {
+        parts.push(format!("{} modified file{}", status.dirty, plural(status.dirty)));
     }
-
-    print!("\n{} ", "Enter number:".bold());
-    io::stdout().flush()?;
-
-    let mut input = String::new();
-    io::stdin().read_line(&mut input)?;
-    let input = input.trim();
-
-    if input.is_empty() {
-        bail!("No selection made.");
+    if status.untracked > 0 {
+        parts.push(format!(
+            "{} untracked file{}",
+            status.untracked,
+            plural(status.untracked)
+        ));
     }
-
-    let idx: usize = input
-        .parse()
-        .map_err(|_| anyhow::anyhow!("Invalid number: {}", input))?;
-
-    if idx == 0 || idx > worktrees.len() {
-        bail!("Invalid selection: {}. Choose 1-{}", idx, worktrees.len());
+    if status.ahead > 0 {
+        parts.push(format!(
+            "{} unpushed commit{}",
+            status.ahead,
+            plural(status.ahead as usize)
+        ));
     }
+    parts.join(", ")
+}
 
-    Ok(worktrees[idx - 1].clone())
+/// `"s"` unless `n` is exactly one.
+fn plural(n: usize) -> &'static str {
+    if n == 1 {
+        ""
+    } else {
+        "s"
+    }
 }
 
 /// Kill tmux session by trying effective name first, then falling back to directory name
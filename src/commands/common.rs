@@ -1,6 +1,8 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
+use colored::Colorize;
+use std::io::{self, IsTerminal, Write};
 
-use crate::config::state::WorktreeState;
+use crate::config::state::{ProcessStopResult, WorktreeState};
 use crate::git;
 use crate::ports;
 use crate::scripts;
@@ -20,6 +22,8 @@ pub struct RemoveResult {
     pub deallocated_ports: Option<Vec<u16>>,
     /// Whether the git worktree was removed successfully
     pub worktree_removed: bool,
+    /// Per-process outcomes of stopping tracked background processes, if any were tracked
+    pub stopped_processes: Option<Vec<ProcessStopResult>>,
 }
 
 /// Remove a worktree: run close script, deallocate ports, and remove the git worktree
@@ -29,17 +33,58 @@ pub fn remove_worktree(state: &WorktreeState, options: &RemoveOptions) -> Result
         close_script_success: None,
         deallocated_ports: None,
         worktree_removed: false,
+        stopped_processes: None,
     };
 
-    // Run close script if it exists
-    let close_script = state.worktree_dir.join(".worktree").join("close.sh");
-    if close_script.exists() {
+    // Run the close phase: prefer the declarative manifest, fall back to close.sh.
+    let manifest = scripts::manifest::Manifest::discover(&state.worktree_dir)?;
+    if let Some(manifest) = manifest
+        .as_ref()
+        .filter(|m| m.has_phase(scripts::manifest::Phase::Close))
+    {
         if options.verbose {
-            println!("  Running close script...");
+            println!("  Running close phase...");
         }
-        let env = scripts::build_env_vars(state);
-        let success = scripts::execute_script_ignore_errors(&close_script, &env);
+        // Cleanup is best-effort; a failing phase must not block removal.
+        let success = manifest
+            .run_phase(scripts::manifest::Phase::Close, state)
+            .is_ok();
         result.close_script_success = Some(success);
+    } else {
+        let close_script = state.worktree_dir.join(".worktree").join("close.sh");
+        if close_script.exists() {
+            if options.verbose {
+                println!("  Running close script...");
+            }
+            let env = scripts::build_env_vars(state);
+            let success = scripts::execute_script_ignore_errors(&close_script, &env);
+            result.close_script_success = Some(success);
+        }
+    }
+
+    // Stop any background services we started so they don't outlive the
+    // worktree and keep holding its ports.
+    if !state.processes.is_empty() {
+        if options.verbose {
+            println!("  Stopping tracked processes...");
+        }
+        if let Ok(results) = state.stop_tracked_processes(true) {
+            if options.verbose {
+                for res in &results {
+                    if res.was_running {
+                        println!("  {} Stopped {} (pid {})", "✓".green(), res.label, res.pid);
+                    } else {
+                        println!(
+                            "  {} {} (pid {}) already exited",
+                            "⚠".yellow(),
+                            res.label,
+                            res.pid
+                        );
+                    }
+                }
+            }
+            result.stopped_processes = Some(results);
+        }
     }
 
     // Deallocate ports
@@ -73,3 +118,157 @@ pub fn remove_worktree(state: &WorktreeState, options: &RemoveOptions) -> Result
 
     Ok(result)
 }
+
+/// Interactively choose a worktree from `worktrees`. On a TTY this runs a
+/// skim fuzzy finder with a preview of the worktree's full status; otherwise it
+/// falls back to a numbered prompt so the selection still works when piped or
+/// run without a terminal. `action` names the operation (e.g. `"close"`) for
+/// the prompt text.
+pub fn select_worktree(worktrees: &[WorktreeState], action: &str) -> Result<WorktreeState> {
+    if worktrees.is_empty() {
+        bail!("No worktrees available.");
+    }
+
+    if io::stdin().is_terminal() && io::stdout().is_terminal() {
+        select_with_skim(worktrees)
+    } else {
+        select_numbered(worktrees, action)
+    }
+}
+
+/// One-line label for a worktree in the picker, e.g.
+/// `myproj/swift-fox [worktree/swift-fox] (ports 3000-3003)`.
+fn picker_line(wt: &WorktreeState) -> String {
+    let ports = if wt.ports.is_empty() {
+        "no ports".to_string()
+    } else {
+        format!(
+            "ports {}-{}",
+            wt.ports.first().unwrap(),
+            wt.ports.last().unwrap()
+        )
+    };
+    format!(
+        "{}/{} [{}] ({})",
+        wt.project_name,
+        wt.effective_name(),
+        wt.branch,
+        ports
+    )
+}
+
+/// Run the skim fuzzy finder over the worktrees and return the chosen one.
+fn select_with_skim(worktrees: &[WorktreeState]) -> Result<WorktreeState> {
+    use skim::prelude::*;
+
+    let options = SkimOptionsBuilder::default()
+        .height(Some("60%"))
+        .reverse(true)
+        .preview(Some(""))
+        .build()
+        .map_err(|e| anyhow::anyhow!("Failed to build skim options: {}", e))?;
+
+    let (tx, rx): (SkimItemSender, SkimItemReceiver) = unbounded();
+    for (index, wt) in worktrees.iter().enumerate() {
+        let item: Arc<dyn SkimItem> = Arc::new(WorktreeItem {
+            index,
+            label: picker_line(wt),
+            preview: preview_text(wt),
+        });
+        let _ = tx.send(item);
+    }
+    drop(tx);
+
+    let output = Skim::run_with(&options, Some(rx))
+        .filter(|out| !out.is_abort)
+        .ok_or_else(|| anyhow::anyhow!("No selection made."))?;
+
+    let selected = output
+        .selected_items
+        .first()
+        .and_then(|item| (**item).as_any().downcast_ref::<WorktreeItem>())
+        .map(|item| item.index)
+        .ok_or_else(|| anyhow::anyhow!("No selection made."))?;
+
+    Ok(worktrees[selected].clone())
+}
+
+/// A worktree presented as a skim item: a searchable one-line label plus a
+/// multi-line preview of its status.
+struct WorktreeItem {
+    index: usize,
+    label: String,
+    preview: String,
+}
+
+impl skim::SkimItem for WorktreeItem {
+    fn text(&self) -> std::borrow::Cow<str> {
+        std::borrow::Cow::Borrowed(&self.label)
+    }
+
+    fn preview(&self, _context: skim::PreviewContext) -> skim::ItemPreview {
+        skim::ItemPreview::Text(self.preview.clone())
+    }
+}
+
+/// Render the preview-pane text for a worktree: its directories, ports, branch,
+/// and live git state when it can be determined.
+fn preview_text(wt: &WorktreeState) -> String {
+    let mut lines = vec![
+        format!("Worktree: {}", wt.effective_name()),
+        format!("Branch:   {}", wt.branch),
+        format!("Project:  {}", wt.project_name),
+        format!("Path:     {}", wt.worktree_dir.display()),
+    ];
+    if wt.ports.is_empty() {
+        lines.push("Ports:    none".to_string());
+    } else {
+        lines.push(format!(
+            "Ports:    {}-{}",
+            wt.ports.first().unwrap(),
+            wt.ports.last().unwrap()
+        ));
+    }
+    if let Ok(status) = git::get_worktree_status(&wt.worktree_dir) {
+        let state = if status.is_clean() {
+            "clean".to_string()
+        } else {
+            format!(
+                "{} dirty, {} untracked, ↑{} ↓{}",
+                status.dirty, status.untracked, status.ahead, status.behind
+            )
+        };
+        lines.push(format!("Status:   {}", state));
+    }
+    lines.join("\n")
+}
+
+/// Numbered-prompt fallback used when no interactive terminal is available.
+fn select_numbered(worktrees: &[WorktreeState], action: &str) -> Result<WorktreeState> {
+    println!("\n{}", format!("Select worktree to {}:", action).bold());
+
+    for (i, wt) in worktrees.iter().enumerate() {
+        println!("  {}) {}", (i + 1).to_string().cyan(), picker_line(wt));
+    }
+
+    print!("\n{} ", "Enter number:".bold());
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    let input = input.trim();
+
+    if input.is_empty() {
+        bail!("No selection made.");
+    }
+
+    let idx: usize = input
+        .parse()
+        .map_err(|_| anyhow::anyhow!("Invalid number: {}", input))?;
+
+    if idx == 0 || idx > worktrees.len() {
+        bail!("Invalid selection: {}. Choose 1-{}", idx, worktrees.len());
+    }
+
+    Ok(worktrees[idx - 1].clone())
+}
@@ -10,7 +10,7 @@ use crate::config::{
 use crate::git;
 use crate::scripts;
 
-pub fn execute(defaults: bool, no_scripts: bool) -> Result<()> {
+pub fn execute(defaults: bool, no_scripts: bool, reconfigure: bool) -> Result<()> {
     // Check if we're in a git repository
     if !git::is_git_repo() {
         bail!("Not in a git repository. Please run this command from within a git repository.");
@@ -20,28 +20,47 @@ pub fn execute(defaults: bool, no_scripts: bool) -> Result<()> {
     let config_dir = paths::project_config_dir_in(&repo_root);
 
     // Check if already initialized
-    if config_dir.exists() && paths::settings_file_in(&repo_root).exists() {
+    let already_initialized =
+        config_dir.exists() && paths::settings_file_in(&repo_root).exists();
+    if already_initialized && !reconfigure {
         bail!(
-            "Worktree configuration already exists at {}\nTo reinitialize, remove the .worktree directory first.",
-            config_dir.display()
+            "Worktree configuration already exists at {}\nRe-run with {} to update it (settings.json and settings.local.json are backed up; other generated files are overwritten), or remove the .worktree directory first.",
+            config_dir.display(),
+            "--reconfigure".cyan()
         );
     }
 
-    println!("{}", "Initializing worktree configuration...".bold());
+    if reconfigure && already_initialized {
+        println!("{}", "Reconfiguring worktree configuration...".bold());
+    } else {
+        println!("{}", "Initializing worktree configuration...".bold());
+    }
     println!();
 
+    // When reconfiguring, pre-fill prompts with the project's current values.
+    let current_settings = if reconfigure && already_initialized {
+        Settings::load_from(&repo_root).unwrap_or_default()
+    } else {
+        Settings::default()
+    };
+    let current_local = if reconfigure && already_initialized {
+        LocalSettings::load_from(&repo_root).unwrap_or_default()
+    } else {
+        LocalSettings::default()
+    };
+
     // Get settings
     let settings = if defaults {
-        Settings::default()
+        current_settings
     } else {
-        prompt_settings()?
+        prompt_settings(current_settings)?
     };
 
     // Get local settings
     let local_settings = if defaults {
-        LocalSettings::default()
+        current_local
     } else {
-        prompt_local_settings()?
+        prompt_local_settings(current_local)?
     };
 
     // Create config directory (if not exists)
@@ -93,10 +112,10 @@ pub fn execute(defaults: bool, no_scripts: bool) -> Result<()> {
     Ok(())
 }
 
-fn prompt_settings() -> Result<Settings> {
-    let mut settings = Settings::default();
+fn prompt_settings(current: Settings) -> Result<Settings> {
+    let mut settings = current;
 
-    println!("Configure project worktree settings (press Enter for defaults):");
+    println!("Configure project worktree settings (press Enter to keep current values):");
     println!();
 
     // Port count
@@ -139,15 +158,46 @@ fn prompt_settings() -> Result<Settings> {
         settings.branch_prefix = input;
     }
 
+    // Seed a sensible default for file provisioning so the feature is
+    // discoverable; users can edit .worktree/settings.json afterwards. Only
+    // seed when empty so reconfigure preserves existing entries.
+    if settings.special_paths.is_empty() {
+        settings
+            .special_paths
+            .insert(".env*".to_string(), crate::provision::ProvisionMode::Copy);
+    }
+
+    // Seed a couple of example verbs so `worktree run <name>` is discoverable.
+    if settings.verbs.is_empty() {
+        use crate::config::settings::VerbConf;
+        settings.verbs = vec![
+            VerbConf {
+                name: "logs".to_string(),
+                alias: Some("l".to_string()),
+                command: "tail -f {worktree_dir}/*.log".to_string(),
+                in_repo_root: false,
+            },
+            VerbConf {
+                name: "test".to_string(),
+                alias: Some("t".to_string()),
+                command: "echo 'configure a test command in .worktree/settings.json'".to_string(),
+                in_repo_root: false,
+            },
+        ];
+    }
+
     println!();
 
     Ok(settings)
 }
 
-fn prompt_local_settings() -> Result<LocalSettings> {
-    let mut local_settings = LocalSettings::default();
+fn prompt_local_settings(current: LocalSettings) -> Result<LocalSettings> {
+    let mut local_settings = current;
 
-    let default_dir = paths::global_worktrees_dir()?;
+    let default_dir = match &local_settings.worktree_dir {
+        Some(dir) => dir.clone(),
+        None => paths::global_worktrees_dir()?,
+    };
     println!(
         "  Default worktree directory: {}",
         default_dir.display().to_string().dimmed()
@@ -1,13 +1,15 @@
 use anyhow::Result;
 use colored::Colorize;
+use serde::Serialize;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use walkdir::WalkDir;
 
 use crate::config::{paths, state::WorktreeState};
-use crate::git;
+use crate::git::{self, WorktreeStatus};
 use crate::ports::PortAllocations;
 
-pub fn execute(json: bool, all: bool) -> Result<()> {
+pub fn execute(json: bool, all: bool, no_status: bool, tag: Option<String>) -> Result<()> {
     // Clean up stale allocations
     let mut allocations = PortAllocations::load()?;
     let stale = allocations.cleanup_stale();
@@ -25,6 +27,11 @@ pub fn execute(json: bool, all: bool) -> Result<()> {
         worktrees.retain(|wt| &wt.project_name == project);
     }
 
+    // Tag filtering cuts across projects before the table groups by project.
+    if let Some(ref tag) = tag {
+        worktrees.retain(|wt| wt.has_tag(tag));
+    }
+
     if worktrees.is_empty() {
         if json {
             println!("[]");
@@ -39,10 +46,22 @@ pub fn execute(json: bool, all: bool) -> Result<()> {
         return Ok(());
     }
 
+    // Collect git status per worktree unless the caller opted out. Collection
+    // is best-effort: a broken worktree is simply omitted from the map rather
+    // than aborting the whole listing.
+    let mut statuses: HashMap<PathBuf, WorktreeStatus> = HashMap::new();
+    if !no_status {
+        for wt in &worktrees {
+            if let Ok(status) = git::get_worktree_status(&wt.worktree_dir) {
+                statuses.insert(wt.worktree_dir.clone(), status);
+            }
+        }
+    }
+
     if json {
-        display_json(&worktrees)?;
+        display_json(&worktrees, &statuses)?;
     } else {
-        display_table(&worktrees, current_project.is_some());
+        display_table(&worktrees, &statuses, current_project.is_some());
     }
 
     Ok(())
@@ -93,7 +112,11 @@ fn find_all_worktrees() -> Result<Vec<WorktreeState>> {
     Ok(worktrees)
 }
 
-fn display_table(worktrees: &[WorktreeState], filtered_by_project: bool) {
+fn display_table(
+    worktrees: &[WorktreeState],
+    statuses: &HashMap<PathBuf, WorktreeStatus>,
+    filtered_by_project: bool,
+) {
     // Group by project
     let mut by_project: HashMap<String, Vec<&WorktreeState>> = HashMap::new();
     for wt in worktrees {
@@ -131,11 +154,20 @@ fn display_table(worktrees: &[WorktreeState], filtered_by_project: bool) {
                     wt.name.green().to_string()
                 };
 
+                // VCS health markers, when status was collected and non-clean.
+                let status_markers = statuses
+                    .get(&wt.worktree_dir)
+                    .map(|s| s.markers())
+                    .filter(|m| !m.is_empty())
+                    .map(|m| format!(" {}", m))
+                    .unwrap_or_default();
+
                 println!(
-                    "  {} {} {}",
+                    "  {} {} {}{}",
                     name_display,
                     format!("({})", port_range).dimmed(),
-                    format!("[{}]", wt.branch).cyan()
+                    format!("[{}]", wt.branch).cyan(),
+                    status_markers
                 );
 
                 println!("    {} {}", "dir:".dimmed(), wt.worktree_dir.display());
@@ -174,8 +206,27 @@ fn display_table(worktrees: &[WorktreeState], filtered_by_project: bool) {
     }
 }
 
-fn display_json(worktrees: &[WorktreeState]) -> Result<()> {
-    let json = serde_json::to_string_pretty(worktrees)?;
+/// A worktree plus its collected git status, for JSON output.
+#[derive(Serialize)]
+struct WorktreeListing<'a> {
+    #[serde(flatten)]
+    state: &'a WorktreeState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<WorktreeStatus>,
+}
+
+fn display_json(
+    worktrees: &[WorktreeState],
+    statuses: &HashMap<PathBuf, WorktreeStatus>,
+) -> Result<()> {
+    let listings: Vec<WorktreeListing> = worktrees
+        .iter()
+        .map(|wt| WorktreeListing {
+            state: wt,
+            status: statuses.get(&wt.worktree_dir).copied(),
+        })
+        .collect();
+    let json = serde_json::to_string_pretty(&listings)?;
     println!("{}", json);
     Ok(())
 }
@@ -1,15 +1,33 @@
 use anyhow::{bail, Context, Result};
 use colored::Colorize;
+use std::collections::HashSet;
 use std::io::{self, Write};
 
-use crate::config::{paths, settings::MergedSettings, state::WorktreeState};
+use crate::config::{
+    paths,
+    settings::MergedSettings,
+    state::{RemoteHost, WorktreeState},
+};
 use crate::git;
 use crate::names;
 use crate::ports;
 use crate::scripts;
 use crate::terminal;
 
-pub fn execute(param: Option<String>) -> Result<()> {
+pub fn execute(
+    param: Option<String>,
+    branch_opt: Option<String>,
+    from_opt: Option<String>,
+    ssh_host: Option<String>,
+    ssh_port: Option<u16>,
+    remote_path: Option<std::path::PathBuf>,
+) -> Result<()> {
+    let ssh_host = ssh_host.map(|host| RemoteHost {
+        host,
+        port: ssh_port,
+        path: remote_path,
+    });
+
     // Check if we're in a git repository
     if !git::is_git_repo() {
         bail!("Not in a git repository. Please run this command from within a git repository.");
@@ -18,7 +36,7 @@ pub fn execute(param: Option<String>) -> Result<()> {
     // Use main repo root to ensure worktrees are created from the main project,
     // even when running from within an existing worktree
     let repo_root = git::get_main_repo_root()?;
-    let project_name = git::get_main_project_name()?;
+    let project_name = names::resolve_project_name(None)?;
 
     // Check if project is initialized
     let config_dir = paths::project_config_dir_in(&repo_root);
@@ -33,7 +51,7 @@ pub fn execute(param: Option<String>) -> Result<()> {
 
         if input.is_empty() || input == "y" || input == "yes" {
             // Run init with defaults=false, no_scripts=false
-            super::init::execute(false, false)?;
+            super::init::execute(false, false, false)?;
             println!();
         } else {
             bail!(
@@ -46,16 +64,24 @@ pub fn execute(param: Option<String>) -> Result<()> {
     // Load settings
     let settings = MergedSettings::load_from(&repo_root)?;
 
-    // Generate worktree name
-    let worktree_name = names::generate();
-
-    // Determine branch name
-    let branch = format!("{}{}", settings.branch_prefix, worktree_name);
+    // Collect existing worktree directory names so generated/slugified names
+    // never collide with a worktree that already exists.
+    let existing: HashSet<String> = git::list_git_worktrees()
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|wt| {
+            wt.path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .map(|s| s.to_string())
+        })
+        .collect();
 
-    // Check if branch already exists
-    if git::branch_exists(&branch) {
-        bail!("Branch {} already exists", branch);
-    }
+    // Resolve the branch and worktree name from any base reference the caller
+    // passed, falling back to a scratch branch named after the display param.
+    let plan = plan_branch(&settings, branch_opt, from_opt, param.as_deref(), &existing)?;
+    let worktree_name = plan.worktree_name.clone();
+    let branch = plan.branch.clone();
 
     // Calculate worktree path
     let worktree_base = settings.get_worktree_base_dir(&project_name)?;
@@ -77,19 +103,58 @@ pub fn execute(param: Option<String>) -> Result<()> {
     println!("  {} {}", "Branch:".dimmed(), branch.cyan());
     println!("  {} {}", "Path:".dimmed(), worktree_dir.display());
 
-    // Create git worktree
+    // Create git worktree according to the resolved plan.
     println!();
     println!("  Creating git worktree...");
-    git::create_worktree(&worktree_dir, &branch)?;
+    match &plan.action {
+        BranchAction::NewBranch => git::create_worktree(&worktree_dir, &branch)?,
+        BranchAction::NewBranchFrom(base_ref) => {
+            git::create_worktree_from(&worktree_dir, &branch, base_ref)?
+        }
+        BranchAction::AttachExisting => git::add_worktree_existing(&worktree_dir, &branch)?,
+        BranchAction::FetchTrack { remote, refspec } => {
+            println!("  Fetching {}/{}...", remote, refspec);
+            git::fetch(remote, refspec)?;
+            git::create_worktree_from(&worktree_dir, &branch, &format!("{}/{}", remote, refspec))?;
+        }
+    }
     println!("  {} Git worktree created", "✓".green());
 
-    // Allocate ports
-    let allocation_key = format!("{}/{}", project_name, worktree_name);
+    // Configure a per-worktree git identity when one is resolved, so commits
+    // made in the worktree don't inherit an unexpected global identity.
+    if let Some(user) = settings.user.as_ref().filter(|u| !u.is_empty()) {
+        if let Some(name) = user.name.as_deref() {
+            git::set_config(&worktree_dir, "user.name", name)?;
+        }
+        if let Some(email) = user.email.as_deref() {
+            git::set_config(&worktree_dir, "user.email", email)?;
+        }
+        println!("  {} Git identity configured", "✓".green());
+    }
+
+    // Populate submodules and vendored subtrees before any setup script runs,
+    // since worktrees start with empty submodule directories and setup often
+    // expects that code to be present.
+    if settings.init_submodules && git::has_submodules(&worktree_dir) {
+        match git::init_submodules(&worktree_dir) {
+            Ok(_) => println!("  {} Submodules initialized", "✓".green()),
+            Err(e) => println!("  {} Submodule init failed: {}", "⚠".yellow(), e),
+        }
+    }
+    init_subtrees(&worktree_dir);
+
+    // Allocate ports, namespaced by host so a remote worktree's ports don't
+    // collide with (or get excluded by) ports allocated on the local machine.
+    let allocation_key = match &ssh_host {
+        Some(remote) => format!("{}/{}/{}", remote.namespace(), project_name, worktree_name),
+        None => format!("{}/{}", project_name, worktree_name),
+    };
     let allocation = ports::allocate(
         settings.port_count,
         &allocation_key,
         settings.port_range_start,
         settings.port_range_end,
+        ssh_host.as_ref().map(|r| r.namespace()),
     )?;
 
     if allocation.existing {
@@ -115,22 +180,54 @@ pub fn execute(param: Option<String>) -> Result<()> {
     .ports(allocation.ports.clone())
     .param(param.clone())
     .display_name(param.clone())
+    .ssh_host(ssh_host.clone())
     .build();
 
     // Save state to worktree
     state.save()?;
     println!("  {} State saved", "✓".green());
 
-    // Run setup script if it exists
-    let setup_script = worktree_dir.join(".worktree").join("setup.sh");
-    if setup_script.exists() {
+    // Provision untracked files (.env, credentials, ...) that git worktrees
+    // don't carry over
+    if !settings.special_paths.is_empty() {
+        match crate::provision::provision_worktree(
+            &repo_root,
+            &worktree_dir,
+            &settings.special_paths,
+            &settings.exclude,
+        ) {
+            Ok(summary) => {
+                for line in &summary {
+                    println!("  {} {}", "✓".green(), line);
+                }
+            }
+            Err(e) => println!("  {} Provisioning failed: {}", "⚠".yellow(), e),
+        }
+    }
+
+    // Run the setup phase: prefer the declarative manifest, fall back to setup.sh.
+    let manifest = scripts::manifest::Manifest::discover(&worktree_dir)?;
+    if let Some(manifest) = manifest
+        .as_ref()
+        .filter(|m| m.has_phase(scripts::manifest::Phase::Setup))
+    {
         println!();
-        println!("  Running setup script...");
-        let env = scripts::build_env_vars(&state);
-        match scripts::execute_script(&setup_script, &env) {
+        println!("  Running setup phase...");
+        match manifest.run_phase(scripts::manifest::Phase::Setup, &state) {
             Ok(_) => println!("  {} Setup complete", "✓".green()),
             Err(e) => println!("  {} Setup failed: {}", "⚠".yellow(), e),
         }
+    } else {
+        let setup_script = worktree_dir.join(".worktree").join("setup.sh");
+        if setup_script.exists() {
+            println!();
+            println!("  Running setup script...");
+            let env = scripts::build_env_vars(&state);
+            match scripts::execute_script(&setup_script, &env) {
+                Ok(_) => println!("  {} Setup complete", "✓".green()),
+                Err(e) => println!("  {} Setup failed: {}", "⚠".yellow(), e),
+            }
+        }
     }
 
     // Launch terminal if configured
@@ -147,10 +244,23 @@ pub fn execute(param: Option<String>) -> Result<()> {
             println!("  Launching {}...", term.name());
             // Use effective name (display name if set, otherwise directory name) for tmux session
             let effective_name = state.effective_name();
+            let shell = terminal::ShellCommand::from_config(&settings.shell);
+            let launch_dir = settings
+                .working_directory
+                .resolve(&worktree_dir, &repo_root);
+            let remote = state.ssh_host.as_ref();
             let launch_result = if term == terminal::Terminal::Tmux {
-                terminal::launch_tmux_session(&project_name, effective_name, &worktree_dir)
+                terminal::launch_tmux_session(
+                    &project_name,
+                    effective_name,
+                    &launch_dir,
+                    &shell,
+                    remote,
+                    terminal::AttachOptions::default(),
+                    scripts::tmux_conf_path(&worktree_dir).as_deref(),
+                )
             } else {
-                terminal::launch(&term, &worktree_dir)
+                terminal::launch(&term, &launch_dir, &shell, remote)
             };
 
             if let Err(e) = launch_result {
@@ -200,3 +310,162 @@ pub fn execute(param: Option<String>) -> Result<()> {
 
     Ok(())
 }
+
+/// A vendored dependency declared in `.worktree/subtrees.json`, pulled into the
+/// worktree with `git subtree add` so worktrees reproduce third-party code
+/// consistently. Mirrors the `.gitsubtrees` prefix/repository/ref tracking.
+#[derive(serde::Deserialize)]
+struct SubtreeEntry {
+    prefix: String,
+    repository: String,
+    #[serde(rename = "ref")]
+    reference: String,
+}
+
+/// Pull in any subtrees declared in `.worktree/subtrees.json`. Missing or
+/// malformed manifests are reported but never abort worktree creation.
+fn init_subtrees(worktree_dir: &std::path::Path) {
+    let manifest = worktree_dir.join(".worktree").join("subtrees.json");
+    if !manifest.exists() {
+        return;
+    }
+
+    let entries: Vec<SubtreeEntry> = match std::fs::read_to_string(&manifest)
+        .map_err(|e| e.to_string())
+        .and_then(|c| serde_json::from_str(&c).map_err(|e| e.to_string()))
+    {
+        Ok(entries) => entries,
+        Err(e) => {
+            println!("  {} Failed to read subtrees.json: {}", "⚠".yellow(), e);
+            return;
+        }
+    };
+
+    for entry in entries {
+        match git::add_subtree(
+            worktree_dir,
+            &entry.prefix,
+            &entry.repository,
+            &entry.reference,
+        ) {
+            Ok(_) => println!(
+                "  {} Subtree {} ({})",
+                "✓".green(),
+                entry.prefix,
+                entry.reference.dimmed()
+            ),
+            Err(e) => println!("  {} Subtree {} failed: {}", "⚠".yellow(), entry.prefix, e),
+        }
+    }
+}
+
+/// How the worktree's branch should be materialized.
+enum BranchAction {
+    /// Create a fresh branch from the current HEAD.
+    NewBranch,
+    /// Create a fresh branch based on an existing ref.
+    NewBranchFrom(String),
+    /// Attach to a local branch that already exists.
+    AttachExisting,
+    /// Fetch a remote branch, then create a local branch tracking it.
+    FetchTrack { remote: String, refspec: String },
+}
+
+/// The resolved branch, worktree directory name, and creation action.
+struct BranchPlan {
+    worktree_name: String,
+    branch: String,
+    action: BranchAction,
+}
+
+/// Decide the branch and worktree name from the optional `--branch`/`--from`
+/// arguments, falling back to a scratch branch when neither is given. The
+/// fallback name is derived from `param` (slugified) when one was supplied, or
+/// a random generator otherwise, and is always kept unique against `existing`
+/// worktree directory names.
+fn plan_branch(
+    settings: &MergedSettings,
+    branch_opt: Option<String>,
+    from_opt: Option<String>,
+    param: Option<&str>,
+    existing: &HashSet<String>,
+) -> Result<BranchPlan> {
+    // `--branch <name>`: use (or create) a named local branch.
+    if let Some(branch) = branch_opt {
+        let action = if git::local_branch_exists(&branch) {
+            BranchAction::AttachExisting
+        } else {
+            BranchAction::NewBranch
+        };
+        return Ok(BranchPlan {
+            worktree_name: names::slugify_stem(&branch),
+            branch,
+            action,
+        });
+    }
+
+    // `--from <ref>`: base the worktree on an existing local or remote ref.
+    if let Some(reference) = from_opt {
+        if git::remote_branch_exists(&reference) {
+            // `origin/feature/x` → remote `origin`, local branch `feature/x`.
+            let (remote, refspec) = reference
+                .split_once('/')
+                .context("Remote ref must be of the form <remote>/<branch>")?;
+            return Ok(BranchPlan {
+                worktree_name: names::slugify_stem(refspec),
+                branch: refspec.to_string(),
+                action: BranchAction::FetchTrack {
+                    remote: remote.to_string(),
+                    refspec: refspec.to_string(),
+                },
+            });
+        }
+
+        if git::local_branch_exists(&reference) {
+            return Ok(BranchPlan {
+                worktree_name: names::slugify_stem(&reference),
+                branch: reference.clone(),
+                action: BranchAction::AttachExisting,
+            });
+        }
+
+        // Unknown ref: fall back to a generated name, basing the new branch on
+        // whatever the reference resolves to.
+        let worktree_name = fallback_name(param, existing);
+        let branch = format!("{}{}", settings.branch_prefix, worktree_name);
+        return Ok(BranchPlan {
+            worktree_name,
+            branch,
+            action: BranchAction::NewBranchFrom(reference),
+        });
+    }
+
+    // Default: a scratch branch off the current HEAD.
+    let worktree_name = fallback_name(param, existing);
+    let branch = format!("{}{}", settings.branch_prefix, worktree_name);
+    if git::branch_exists(&branch) {
+        bail!("Branch {} already exists", branch);
+    }
+    Ok(BranchPlan {
+        worktree_name,
+        branch,
+        action: BranchAction::NewBranch,
+    })
+}
+
+/// Pick the worktree directory name when no branch ref dictates it: a
+/// slugified `param` when the user gave a display name, otherwise a random
+/// adjective-noun name. Either way the result avoids colliding with an
+/// `existing` worktree directory.
+fn fallback_name(param: Option<&str>, existing: &HashSet<String>) -> String {
+    match param {
+        Some(p) => {
+            let mut name = names::slugify(p);
+            while existing.contains(&name) {
+                name = names::slugify(p);
+            }
+            name
+        }
+        None => names::generate_unique(existing),
+    }
+}
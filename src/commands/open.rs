@@ -1,14 +1,22 @@
 use anyhow::{bail, Result};
 use colored::Colorize;
-use std::io::{self, Write};
 use walkdir::WalkDir;
 
 use crate::config::{paths, settings::MergedSettings, state::WorktreeState};
 use crate::terminal;
 
-pub fn execute(name: Option<String>, interactive: bool) -> Result<()> {
+pub fn execute(
+    name: Option<String>,
+    interactive: bool,
+    read_only: bool,
+    detach_others: bool,
+) -> Result<()> {
     // Determine which worktree to open
     let worktree_state = resolve_worktree(name, interactive)?;
+    let attach = terminal::AttachOptions {
+        read_only,
+        detach_others,
+    };
 
     println!(
         "{} {}/{}",
@@ -32,6 +40,13 @@ pub fn execute(name: Option<String>, interactive: bool) -> Result<()> {
             auto_launch_terminal: true,
             worktree_dir: None,
             terminal: None,
+            special_paths: std::collections::HashMap::new(),
+            exclude: Vec::new(),
+            shell: crate::config::settings::ShellConfig::default(),
+            working_directory: crate::config::settings::WorkingDirectory::default(),
+            verbs: Vec::new(),
+            user: None,
+            init_submodules: true,
         }
     });
 
@@ -45,14 +60,23 @@ pub fn execute(name: Option<String>, interactive: bool) -> Result<()> {
     if let Some(term) = term {
         println!();
         println!("  Launching {}...", term.name());
+        let shell = terminal::ShellCommand::from_config(&settings.shell);
+        let launch_dir = settings
+            .working_directory
+            .resolve(&worktree_state.worktree_dir, &worktree_state.original_dir);
+        let remote = worktree_state.ssh_host.as_ref();
         let launch_result = if term == terminal::Terminal::Tmux {
             terminal::launch_tmux_session(
                 &worktree_state.project_name,
                 &worktree_state.name,
-                &worktree_state.worktree_dir,
+                &launch_dir,
+                &shell,
+                remote,
+                attach,
+                crate::scripts::tmux_conf_path(&worktree_state.worktree_dir).as_deref(),
             )
         } else {
-            terminal::launch(&term, &worktree_state.worktree_dir)
+            terminal::launch(&term, &launch_dir, &shell, remote)
         };
 
         if let Err(e) = launch_result {
@@ -80,7 +104,7 @@ fn resolve_worktree(name: Option<String>, interactive: bool) -> Result<WorktreeS
         if worktrees.is_empty() {
             bail!("No worktrees found.");
         }
-        return select_worktree(&worktrees);
+        return super::common::select_worktree(&worktrees, "open");
     }
 
     // If name provided, find by name
@@ -96,7 +120,7 @@ fn resolve_worktree(name: Option<String>, interactive: bool) -> Result<WorktreeS
             1 => return Ok(matches.into_iter().next().unwrap()),
             _ => {
                 println!("{}", "Multiple worktrees match that name:".yellow());
-                return select_worktree(&matches);
+                return super::common::select_worktree(&matches, "open");
             }
         }
     }
@@ -111,7 +135,7 @@ fn resolve_worktree(name: Option<String>, interactive: bool) -> Result<WorktreeS
     if worktrees.is_empty() {
         bail!("No worktrees found.");
     }
-    select_worktree(&worktrees)
+    super::common::select_worktree(&worktrees, "open")
 }
 
 /// Find all worktrees in the global directory
@@ -140,45 +164,3 @@ fn find_all_worktrees() -> Result<Vec<WorktreeState>> {
     Ok(worktrees)
 }
 
-/// Interactive worktree selection
-fn select_worktree(worktrees: &[WorktreeState]) -> Result<WorktreeState> {
-    println!("\n{}", "Select worktree to open:".bold());
-
-    for (i, wt) in worktrees.iter().enumerate() {
-        let port_range = if wt.ports.is_empty() {
-            "no ports".to_string()
-        } else {
-            format!("{}-{}", wt.ports.first().unwrap(), wt.ports.last().unwrap())
-        };
-
-        println!(
-            "  {}) {}/{} {} {}",
-            (i + 1).to_string().cyan(),
-            wt.project_name.blue(),
-            wt.name.green(),
-            format!("(ports {})", port_range).dimmed(),
-            format!("[{}]", wt.branch).dimmed()
-        );
-    }
-
-    print!("\n{} ", "Enter number:".bold());
-    io::stdout().flush()?;
-
-    let mut input = String::new();
-    io::stdin().read_line(&mut input)?;
-    let input = input.trim();
-
-    if input.is_empty() {
-        bail!("No selection made.");
-    }
-
-    let idx: usize = input
-        .parse()
-        .map_err(|_| anyhow::anyhow!("Invalid number: {}", input))?;
-
-    if idx == 0 || idx > worktrees.len() {
-        bail!("Invalid selection: {}. Choose 1-{}", idx, worktrees.len());
-    }
-
-    Ok(worktrees[idx - 1].clone())
-}
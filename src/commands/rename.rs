@@ -7,7 +7,12 @@ use crate::config::{paths, state::WorktreeState};
 use crate::git;
 use crate::terminal;
 
-pub fn execute(new_name: Option<String>, worktree: Option<String>, clear: bool) -> Result<()> {
+pub fn execute(
+    new_name: Option<String>,
+    worktree: Option<String>,
+    clear: bool,
+    allow_duplicate: bool,
+) -> Result<()> {
     // Resolve which worktree to rename
     let mut state = resolve_worktree(worktree)?;
 
@@ -29,7 +34,9 @@ pub fn execute(new_name: Option<String>, worktree: Option<String>, clear: bool)
 
     // Validate and set the new name
     validate_name(&new_name)?;
-    check_name_conflicts(&new_name, &state)?;
+    if !allow_duplicate {
+        check_name_conflicts(&new_name, &state)?;
+    }
 
     let old_name = state.effective_name().to_string();
     state.display_name = Some(new_name.clone());
@@ -114,21 +121,25 @@ fn validate_name(name: &str) -> Result<()> {
     Ok(())
 }
 
-/// Check for name conflicts with other worktrees
+/// Reject a display name that would collide with another worktree in the same
+/// project, keeping identifiers unambiguous for `resolve_worktree` and tmux
+/// session targeting. A collision against either a worktree's effective name or
+/// its directory name is rejected; pass `--allow-duplicate` to override.
 fn check_name_conflicts(new_name: &str, current: &WorktreeState) -> Result<()> {
-    let worktrees = find_all_worktrees()?;
+    let worktrees = find_worktrees_for_current_project()?;
     for wt in worktrees {
-        // Skip the current worktree
+        // Skip the current worktree.
         if wt.worktree_dir == current.worktree_dir {
             continue;
         }
-        // Check for conflicts with name or display_name
-        if wt.name == new_name || wt.display_name.as_deref() == Some(new_name) {
+        if wt.effective_name() == new_name || wt.name == new_name {
             bail!(
-                "Name '{}' conflicts with existing worktree '{}' in project '{}'",
+                "Name '{}' already used by worktree '{}' in project '{}'. \
+                 Choose another name (e.g. '{}') or pass --allow-duplicate.",
                 new_name,
                 wt.effective_name(),
-                wt.project_name
+                wt.project_name,
+                format!("{}-2", new_name)
             );
         }
     }
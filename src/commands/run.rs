@@ -1,15 +1,26 @@
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
 use colored::Colorize;
 
+use crate::config::settings::{MergedSettings, VerbConf};
 use crate::config::state;
+use crate::process::create_command;
 use crate::scripts;
 
-pub fn execute() -> Result<()> {
+pub fn execute(verb: Option<String>, background: bool) -> Result<()> {
     // Detect if we're in a worktree
-    let worktree_state = state::detect_worktree()?.ok_or_else(|| {
+    let mut worktree_state = state::detect_worktree()?.ok_or_else(|| {
         anyhow::anyhow!("Not in a worktree. Run this command from within a worktree directory.")
     })?;
 
+    // A named verb runs a user-defined command instead of run.sh.
+    if let Some(verb) = verb {
+        let settings = MergedSettings::load_from(&worktree_state.original_dir)?;
+        let conf = settings
+            .find_verb(&verb)
+            .ok_or_else(|| anyhow::anyhow!("No verb named '{}' configured", verb))?;
+        return run_verb(conf, &worktree_state);
+    }
+
     println!(
         "{} {}/{}",
         "Running:".bold(),
@@ -17,6 +28,14 @@ pub fn execute() -> Result<()> {
         worktree_state.name.green()
     );
 
+    // Prefer the declarative manifest when it describes this phase.
+    if let Some(manifest) = scripts::manifest::Manifest::discover(&worktree_state.worktree_dir)? {
+        if manifest.has_phase(scripts::manifest::Phase::Run) {
+            println!();
+            return manifest.run_phase(scripts::manifest::Phase::Run, &worktree_state);
+        }
+    }
+
     // Find run script
     let run_script = worktree_state.worktree_dir.join(".worktree").join("run.sh");
 
@@ -37,7 +56,65 @@ pub fn execute() -> Result<()> {
     );
     println!();
 
-    scripts::execute_script(&run_script, &env)?;
+    if background {
+        // Start the run script in the background and track it so its dev
+        // servers can be stopped reliably on `close` instead of being
+        // orphaned. The caller loses live output and Ctrl-C control.
+        let pid = scripts::spawn_background(&run_script, &env, &mut worktree_state, "run.sh")?;
+        println!("  {} Started run.sh (pid {})", "✓".green(), pid);
+    } else {
+        // Run in the foreground so output streams live and Ctrl-C stops it,
+        // matching run.sh's assumption that it's the session's dev server.
+        scripts::execute_script(&run_script, &env)?;
+    }
+
+    Ok(())
+}
+
+/// Run a user-defined verb, expanding template placeholders and executing the
+/// command in either the worktree or the main repo root.
+fn run_verb(conf: &VerbConf, state: &state::WorktreeState) -> Result<()> {
+    let ports = state
+        .ports
+        .iter()
+        .map(|p| p.to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let command = conf
+        .command
+        .replace("{worktree_dir}", &state.worktree_dir.to_string_lossy())
+        .replace("{ports}", &ports)
+        .replace("{branch}", &state.branch);
+
+    let cwd = if conf.in_repo_root {
+        &state.original_dir
+    } else {
+        &state.worktree_dir
+    };
+
+    println!(
+        "{} {}/{} {}",
+        "Running:".bold(),
+        state.project_name.blue(),
+        state.name.green(),
+        format!("[{}]", conf.name).dimmed()
+    );
+    println!();
+
+    // Expose the same environment the lifecycle scripts receive.
+    let env = scripts::build_env_vars(state);
+    let status = create_command("bash")
+        .arg("-c")
+        .arg(&command)
+        .envs(&env)
+        .current_dir(cwd)
+        .status()
+        .with_context(|| format!("Failed to run verb '{}'", conf.name))?;
+
+    if !status.success() {
+        bail!("Verb '{}' exited with status: {}", conf.name, status.code().unwrap_or(-1));
+    }
 
     Ok(())
 }
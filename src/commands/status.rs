@@ -1,11 +1,13 @@
 use anyhow::{bail, Result};
 use colored::Colorize;
+use serde::Serialize;
 use std::process;
 use walkdir::WalkDir;
 
 use crate::config::{paths, state::WorktreeState};
+use crate::git::{self, WorktreeStatus};
 
-pub fn execute(name: Option<String>) -> Result<()> {
+pub fn execute(name: Option<String>, json: bool) -> Result<()> {
     let worktree_state = match resolve_worktree(name)? {
         Some(state) => state,
         None => {
@@ -14,10 +16,35 @@ pub fn execute(name: Option<String>) -> Result<()> {
         }
     };
 
-    display_status(&worktree_state);
+    // Collect live git info; best-effort so a broken worktree still reports
+    // its static state.
+    let head = git::get_head_sha(&worktree_state.worktree_dir).ok();
+    let status = git::get_worktree_status(&worktree_state.worktree_dir).ok();
+
+    if json {
+        let report = StatusReport {
+            state: &worktree_state,
+            head: head.as_deref(),
+            git: status,
+        };
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        display_status(&worktree_state, head.as_deref(), status.as_ref());
+    }
     Ok(())
 }
 
+/// The full worktree state plus live git info, for `status --json`.
+#[derive(Serialize)]
+struct StatusReport<'a> {
+    #[serde(flatten)]
+    state: &'a WorktreeState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    head: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    git: Option<WorktreeStatus>,
+}
+
 /// Resolve which worktree to show status for
 fn resolve_worktree(name: Option<String>) -> Result<Option<WorktreeState>> {
     // If name provided, find by name
@@ -67,8 +94,8 @@ fn find_all_worktrees() -> Result<Vec<WorktreeState>> {
     Ok(worktrees)
 }
 
-/// Display the status of a worktree
-fn display_status(state: &WorktreeState) {
+/// Display the status of a worktree, including live git info when available.
+fn display_status(state: &WorktreeState, head: Option<&str>, git: Option<&WorktreeStatus>) {
     // Worktree name (show both display name and directory name if different)
     if state.has_custom_name() {
         println!(
@@ -84,6 +111,30 @@ fn display_status(state: &WorktreeState) {
     println!("{} {}", "Branch:  ".bold(), state.branch.cyan());
     println!("{} {}", "Project: ".bold(), state.project_name.blue());
 
+    // Live git state: current HEAD, divergence from upstream, and dirtiness.
+    if let Some(head) = head {
+        println!("{} {}", "HEAD:    ".bold(), head.yellow());
+    }
+    if let Some(status) = git {
+        let tree = if status.dirty == 0 && status.untracked == 0 {
+            "clean".green().to_string()
+        } else {
+            format!(
+                "{} modified, {} untracked",
+                status.dirty, status.untracked
+            )
+            .yellow()
+            .to_string()
+        };
+        println!("{} {}", "Changes: ".bold(), tree);
+        println!(
+            "{} ↑{} ↓{}",
+            "Upstream:".bold(),
+            status.ahead,
+            status.behind
+        );
+    }
+
     println!();
     println!("{}", "Directories:".bold());
     println!(
@@ -17,6 +17,17 @@ pub fn execute() -> Result<()> {
         worktree_state.name.green()
     );
 
+    // Prefer the declarative manifest when it describes this phase.
+    if let Some(manifest) = scripts::manifest::Manifest::discover(&worktree_state.worktree_dir)? {
+        if manifest.has_phase(scripts::manifest::Phase::Stop) {
+            println!();
+            manifest.run_phase(scripts::manifest::Phase::Stop, &worktree_state)?;
+            println!();
+            println!("{}", "Services stopped.".green());
+            return Ok(());
+        }
+    }
+
     // Find stop script
     let stop_script = worktree_state
         .worktree_dir
@@ -0,0 +1,89 @@
+use anyhow::{bail, Result};
+use colored::Colorize;
+use walkdir::WalkDir;
+
+use crate::config::{paths, state::WorktreeState};
+
+pub fn execute(name: Option<String>, add: Vec<String>, remove: Vec<String>) -> Result<()> {
+    let mut state = resolve_worktree(name)?;
+
+    // With no mutations requested, just report the current tags.
+    if add.is_empty() && remove.is_empty() {
+        print_tags(&state);
+        return Ok(());
+    }
+
+    for tag in &remove {
+        state.tags.retain(|t| t != tag);
+    }
+    for tag in add {
+        if !state.tags.contains(&tag) {
+            state.tags.push(tag);
+        }
+    }
+    state.tags.sort();
+    state.save()?;
+
+    println!(
+        "{} Updated tags for {}/{}",
+        "✓".green(),
+        state.project_name.blue(),
+        state.effective_name().green()
+    );
+    print_tags(&state);
+
+    Ok(())
+}
+
+/// Print the worktree's current tags, or a note when it has none.
+fn print_tags(state: &WorktreeState) {
+    if state.tags.is_empty() {
+        println!("  {}", "(no tags)".dimmed());
+    } else {
+        println!("  {} {}", "tags:".dimmed(), state.tags.join(" ").cyan());
+    }
+}
+
+/// Resolve which worktree to tag: by identifier, or the current worktree.
+fn resolve_worktree(identifier: Option<String>) -> Result<WorktreeState> {
+    if let Some(id) = identifier {
+        let matches: Vec<_> = find_all_worktrees()?
+            .into_iter()
+            .filter(|wt| wt.matches_identifier(&id))
+            .collect();
+
+        return match matches.len() {
+            0 => bail!("No worktree found with name '{}'", id),
+            1 => Ok(matches.into_iter().next().unwrap()),
+            _ => bail!("Multiple worktrees match '{}'; be more specific", id),
+        };
+    }
+
+    crate::config::state::detect_worktree()?
+        .ok_or_else(|| anyhow::anyhow!("Not in a worktree. Pass a worktree name to tag."))
+}
+
+/// Find all worktrees across all projects
+fn find_all_worktrees() -> Result<Vec<WorktreeState>> {
+    let mut worktrees = Vec::new();
+    let base_dir = paths::global_worktrees_dir()?;
+
+    if !base_dir.exists() {
+        return Ok(worktrees);
+    }
+
+    for entry in WalkDir::new(&base_dir)
+        .min_depth(1)
+        .max_depth(3)
+        .into_iter()
+        .filter_map(|e| e.ok())
+    {
+        if entry.file_name() == "state.json" {
+            if let Ok(state) = WorktreeState::load(entry.path()) {
+                worktrees.push(state);
+            }
+        }
+    }
+
+    Ok(worktrees)
+}
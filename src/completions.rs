@@ -3,6 +3,7 @@ use walkdir::WalkDir;
 
 use crate::config::{paths, state::WorktreeState};
 use crate::git;
+use crate::ports::PortAllocations;
 
 /// Get worktree name completion candidates
 /// Returns all worktree names, optionally filtered by current project
@@ -15,7 +16,7 @@ pub fn worktree_names() -> Vec<CompletionCandidate> {
     // Try to get the current project to filter results
     let current_project = get_current_project();
 
-    worktrees
+    let mut candidates: Vec<CompletionCandidate> = worktrees
         .into_iter()
         .filter(|wt| {
             // If we know the current project, only show worktrees from that project
@@ -40,6 +41,91 @@ pub fn worktree_names() -> Vec<CompletionCandidate> {
 
             candidates
         })
+        .collect();
+
+    // Also offer worktrees that only have a port allocation recorded (e.g. the
+    // state.json was lost) and any live tmux sessions for this project.
+    candidates.extend(allocation_name_candidates(current_project.as_deref()));
+    candidates.extend(tmux_session_candidates(current_project.as_deref()));
+
+    // De-duplicate by candidate value, keeping the first (richer) entry.
+    let mut seen = std::collections::HashSet::new();
+    candidates.retain(|c| seen.insert(c.get_value().to_os_string()));
+    candidates
+}
+
+/// Tag completion candidates, drawn from the tags recorded on every worktree.
+pub fn tag_values() -> Vec<CompletionCandidate> {
+    let worktrees = match find_all_worktrees() {
+        Ok(wts) => wts,
+        Err(_) => return vec![],
+    };
+
+    let mut candidates = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+    for wt in worktrees {
+        for tag in wt.tags {
+            if seen.insert(tag.clone()) {
+                candidates.push(CompletionCandidate::new(tag));
+            }
+        }
+    }
+    candidates
+}
+
+/// Worktree names drawn from recorded port allocations for the given project.
+fn allocation_name_candidates(current_project: Option<&str>) -> Vec<CompletionCandidate> {
+    let allocations = match PortAllocations::load() {
+        Ok(a) => a,
+        Err(_) => return vec![],
+    };
+
+    allocations
+        .allocations
+        .iter()
+        .filter_map(|(key, ports)| {
+            // Keys are "project/worktree" or "host/project/worktree"; the
+            // worktree name is always the final component.
+            let parts: Vec<&str> = key.split('/').collect();
+            let worktree = parts.last()?;
+            let project = if parts.len() >= 2 {
+                parts[parts.len() - 2]
+            } else {
+                return None;
+            };
+            if current_project.map(|p| p == project).unwrap_or(true) {
+                let help = format!("ports {:?}", ports);
+                Some(CompletionCandidate::new(worktree).help(Some(help.into())))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Worktree names drawn from live tmux sessions matching the given project.
+fn tmux_session_candidates(current_project: Option<&str>) -> Vec<CompletionCandidate> {
+    let output = match crate::process::create_command("tmux")
+        .args(["list-sessions", "-F", "#{session_name}"])
+        .output()
+    {
+        Ok(output) if output.status.success() => output,
+        _ => return vec![],
+    };
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout
+        .lines()
+        .filter_map(|session| {
+            // Sessions are named "project-worktree"; offer the worktree part for
+            // the current project, or the whole name when the project is unknown.
+            match current_project {
+                Some(project) => session
+                    .strip_prefix(&format!("{}-", project))
+                    .map(|worktree| CompletionCandidate::new(worktree).help(Some("tmux".into()))),
+                None => Some(CompletionCandidate::new(session).help(Some("tmux".into()))),
+            }
+        })
         .collect()
 }
 
@@ -75,9 +161,9 @@ fn get_current_project() -> Option<String> {
         return Some(state.project_name);
     }
 
-    // Otherwise try to get the project name from git
+    // Otherwise resolve from the override env var or the git repository name
     if git::is_git_repo() {
-        if let Ok(name) = git::get_main_project_name() {
+        if let Ok(name) = crate::names::resolve_project_name(None) {
             return Some(name);
         }
     }
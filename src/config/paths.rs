@@ -55,6 +55,29 @@ pub fn local_settings_file_in(root: &std::path::Path) -> PathBuf {
     project_config_dir_in(root).join("settings.local.json")
 }
 
+/// Collect the directories that own a `.worktree/settings.json`, walking up from
+/// `start` to the filesystem root. Returned outermost-first so that callers can
+/// merge ancestors before descendants (the nearest directory overrides).
+pub fn ancestor_config_roots(start: &std::path::Path) -> Vec<PathBuf> {
+    let mut roots = Vec::new();
+    let mut current = start.to_path_buf();
+
+    loop {
+        if settings_file_in(&current).exists() {
+            roots.push(current.clone());
+        }
+
+        match current.parent() {
+            Some(parent) if parent != current => current = parent.to_path_buf(),
+            _ => break,
+        }
+    }
+
+    // Walked nearest-first; flip so the outermost ancestor is merged first.
+    roots.reverse();
+    roots
+}
+
 /// Ensures the global directory exists
 pub fn ensure_global_dir() -> Result<()> {
     std::fs::create_dir_all(global_dir()?)?;
@@ -1,11 +1,30 @@
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use colored::Colorize;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 
 use super::paths;
 
+/// Git identity to apply inside created worktrees.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GitUser {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+}
+
+impl GitUser {
+    /// Whether any identity field is set.
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none() && self.email.is_none()
+    }
+}
+
 /// User-scoped settings (~/.config/worktree/config.json)
 /// These are personal preferences that apply across all projects
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -18,6 +37,18 @@ pub struct UserSettings {
     /// Preferred terminal emulator (e.g., "tmux", "iterm2", "ghostty")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub terminal: Option<String>,
+
+    /// Shell to launch inside the terminal (see [`ShellConfig`]).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shell: Option<ShellConfig>,
+
+    /// Where the launched terminal should start (see [`WorkingDirectory`]).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_directory: Option<WorkingDirectory>,
+
+    /// Git identity to apply inside created worktrees.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<GitUser>,
 }
 
 impl UserSettings {
@@ -157,6 +188,7 @@ impl UserSettings {
         let settings = Self {
             auto_launch_terminal: Some(auto_launch_terminal),
             terminal,
+            ..Default::default()
         };
 
         // Save the settings
@@ -197,6 +229,92 @@ impl UserSettings {
     }
 }
 
+/// Shell to launch inside a worktree terminal. Deserializes from either
+/// `"system"` / a bare program name, or `{ "program": ..., "arguments": [...] }`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ShellConfig {
+    /// `"system"` (use `$SHELL`/default) or a bare program name.
+    Program(String),
+    /// A program together with its arguments.
+    WithArguments {
+        program: String,
+        #[serde(default)]
+        arguments: Vec<String>,
+    },
+}
+
+impl Default for ShellConfig {
+    fn default() -> Self {
+        ShellConfig::Program("system".to_string())
+    }
+}
+
+impl ShellConfig {
+    /// Resolve to an explicit program and arguments. Returns `None` for the
+    /// "system" shell, meaning the launcher should fall back to `$SHELL`.
+    pub fn resolve(&self) -> Option<(String, Vec<String>)> {
+        match self {
+            ShellConfig::Program(p) if p == "system" => None,
+            ShellConfig::Program(p) => Some((p.clone(), Vec::new())),
+            ShellConfig::WithArguments { program, arguments } => {
+                Some((program.clone(), arguments.clone()))
+            }
+        }
+    }
+}
+
+/// Where a launched terminal should start.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkingDirectory {
+    /// The new worktree root (default).
+    #[default]
+    Worktree,
+    /// The main repository root.
+    Repo,
+    /// An explicit path.
+    Custom(PathBuf),
+}
+
+impl WorkingDirectory {
+    /// Resolve to a concrete path given the worktree and repository roots.
+    pub fn resolve(&self, worktree_dir: &Path, repo_root: &Path) -> PathBuf {
+        match self {
+            WorkingDirectory::Worktree => worktree_dir.to_path_buf(),
+            WorkingDirectory::Repo => repo_root.to_path_buf(),
+            WorkingDirectory::Custom(path) => path.clone(),
+        }
+    }
+}
+
+/// A user-defined command runnable via `worktree run <name>`. The command
+/// template may contain `{worktree_dir}`, `{ports}`, and `{branch}` placeholders.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerbConf {
+    /// Name used to invoke the verb.
+    pub name: String,
+
+    /// Optional short alias.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+
+    /// Shell invocation template with placeholders.
+    pub command: String,
+
+    /// Run in the main repo root instead of the worktree directory.
+    #[serde(default)]
+    pub in_repo_root: bool,
+}
+
+impl VerbConf {
+    /// Whether this verb is invoked by `identifier` (its name or alias).
+    pub fn matches(&self, identifier: &str) -> bool {
+        self.name == identifier || self.alias.as_deref() == Some(identifier)
+    }
+}
+
 /// Team-shared settings (committed to repo)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -213,6 +331,24 @@ pub struct Settings {
     #[serde(default = "default_branch_prefix")]
     pub branch_prefix: String,
 
+    /// Other settings files to import and merge *before* this file's own
+    /// fields. Relative paths are resolved against this file's directory.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub imports: Vec<PathBuf>,
+
+    /// Glob -> handling mode for untracked files that git worktrees don't carry
+    /// over (e.g. `.env*`, `config/secrets/**`). The most specific glob wins.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub special_paths: HashMap<String, crate::provision::ProvisionMode>,
+
+    /// Globs carving out exceptions to `special_paths`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+
+    /// User-defined commands beyond the fixed lifecycle scripts.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub verbs: Vec<VerbConf>,
+
     /// Terminal settings can be overridden at project level (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub auto_launch_terminal: Option<bool>,
@@ -220,6 +356,22 @@ pub struct Settings {
     /// Terminal to use at project level (optional, overrides user setting)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub terminal: Option<String>,
+
+    /// Shell to launch at project level (optional, overrides user setting)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shell: Option<ShellConfig>,
+
+    /// Launch working directory at project level (optional, overrides user)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_directory: Option<WorkingDirectory>,
+
+    /// Git identity at project level (optional, overrides user setting)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<GitUser>,
+
+    /// Whether new worktrees initialize git submodules recursively.
+    #[serde(default = "default_init_submodules")]
+    pub init_submodules: bool,
 }
 
 impl Default for Settings {
@@ -229,8 +381,16 @@ impl Default for Settings {
             port_range_start: default_port_range_start(),
             port_range_end: default_port_range_end(),
             branch_prefix: default_branch_prefix(),
+            imports: Vec::new(),
+            special_paths: HashMap::new(),
+            exclude: Vec::new(),
+            verbs: Vec::new(),
             auto_launch_terminal: None,
             terminal: None,
+            shell: None,
+            working_directory: None,
+            user: None,
+            init_submodules: default_init_submodules(),
         }
     }
 }
@@ -247,6 +407,9 @@ fn default_port_range_end() -> u16 {
 fn default_branch_prefix() -> String {
     "worktree/".to_string()
 }
+fn default_init_submodules() -> bool {
+    true
+}
 
 /// Personal settings (gitignored)
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -256,6 +419,49 @@ pub struct LocalSettings {
     pub worktree_dir: Option<PathBuf>,
 }
 
+/// Origin of an effective setting value, used by `--print-config` so users can
+/// see why a setting took effect.
+#[derive(Debug, Clone)]
+pub enum SettingSource {
+    /// Built-in default.
+    Default,
+    /// A JSON file on the discovery chain.
+    File(PathBuf),
+    /// An environment variable override (e.g. `WORKTREE_PORT_COUNT`).
+    Env(String),
+}
+
+impl std::fmt::Display for SettingSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingSource::Default => write!(f, "default"),
+            SettingSource::File(path) => write!(f, "{}", path.display()),
+            SettingSource::Env(var) => write!(f, "${}", var),
+        }
+    }
+}
+
+/// Records where each effective setting value came from during resolution.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigProvenance {
+    sources: Vec<(&'static str, SettingSource)>,
+}
+
+impl ConfigProvenance {
+    fn set(&mut self, field: &'static str, source: SettingSource) {
+        if let Some(entry) = self.sources.iter_mut().find(|(name, _)| *name == field) {
+            entry.1 = source;
+        } else {
+            self.sources.push((field, source));
+        }
+    }
+
+    /// The recorded (field, source) pairs, in field declaration order.
+    pub fn entries(&self) -> &[(&'static str, SettingSource)] {
+        &self.sources
+    }
+}
+
 /// Merged settings for runtime use
 #[derive(Debug, Clone)]
 pub struct MergedSettings {
@@ -267,55 +473,65 @@ pub struct MergedSettings {
     pub worktree_dir: Option<PathBuf>,
     /// Terminal to use (e.g., "tmux", "iterm2"). If None, auto-detects.
     pub terminal: Option<String>,
+    /// Glob -> handling mode for file provisioning into new worktrees.
+    pub special_paths: HashMap<String, crate::provision::ProvisionMode>,
+    /// Globs carving out exceptions to `special_paths`.
+    pub exclude: Vec<String>,
+    /// Shell to launch inside worktree terminals.
+    pub shell: ShellConfig,
+    /// Where launched terminals should start.
+    pub working_directory: WorkingDirectory,
+    /// User-defined commands runnable via `worktree run <name>`.
+    pub verbs: Vec<VerbConf>,
+    /// Git identity to apply inside created worktrees, if any.
+    pub user: Option<GitUser>,
+    /// Whether new worktrees initialize git submodules recursively.
+    pub init_submodules: bool,
 }
 
 impl MergedSettings {
+    /// Find a configured verb by its name or alias.
+    pub fn find_verb(&self, identifier: &str) -> Option<&VerbConf> {
+        self.verbs.iter().find(|v| v.matches(identifier))
+    }
     /// Load and merge settings from a specific root directory
     /// Priority: project settings > user settings > defaults
     pub fn load_from(root: &Path) -> Result<Self> {
-        let settings_path = paths::settings_file_in(root);
-        let local_settings_path = paths::local_settings_file_in(root);
-
-        // Load project settings
-        let settings: Settings = if settings_path.exists() {
-            let content = std::fs::read_to_string(&settings_path)
-                .with_context(|| format!("Failed to read {}", settings_path.display()))?;
-            serde_json::from_str(&content)
-                .with_context(|| format!("Failed to parse {}", settings_path.display()))?
-        } else {
-            Settings::default()
-        };
+        Ok(Self::load_with_provenance(root)?.0)
+    }
 
-        // Load project-local settings
-        let local_settings: LocalSettings = if local_settings_path.exists() {
-            let content = std::fs::read_to_string(&local_settings_path)
-                .with_context(|| format!("Failed to read {}", local_settings_path.display()))?;
-            serde_json::from_str(&content)
-                .with_context(|| format!("Failed to parse {}", local_settings_path.display()))?
-        } else {
-            LocalSettings::default()
-        };
+    /// Load merged settings together with the provenance of each effective
+    /// value. The discovery chain, lowest precedence first, is:
+    /// built-in defaults, `~/.config/worktree/config.json`, each ancestor
+    /// `.worktree/settings.json` (outermost first), the nearest
+    /// `.worktree/settings.local.json`, and finally environment variables.
+    pub fn load_with_provenance(root: &Path) -> Result<(Self, ConfigProvenance)> {
+        let mut resolver = SettingsResolver::default();
+
+        // User config (~/.config/worktree/config.json). Load (or prompt for
+        // setup) via UserSettings, then layer its raw fields for provenance.
+        let _ = UserSettings::load_or_setup()?;
+        let user_config = paths::user_config_file()?;
+        if user_config.exists() {
+            resolver.apply_file(&user_config)?;
+        }
 
-        // Load user settings (or prompt for setup if not exists)
-        let user_settings = UserSettings::load_or_setup()?;
+        // Ancestor project settings, outermost directory first so the nearest
+        // one wins.
+        for ancestor in paths::ancestor_config_roots(root) {
+            resolver.apply_file(&paths::settings_file_in(&ancestor))?;
+        }
 
-        // Merge with priority: project > user > default
-        let auto_launch_terminal = settings
-            .auto_launch_terminal
-            .or(user_settings.auto_launch_terminal)
-            .unwrap_or(true);
+        // Nearest project-local settings.
+        let local_settings_path = paths::local_settings_file_in(root);
+        if local_settings_path.exists() {
+            resolver.apply_file(&local_settings_path)?;
+        }
 
-        let terminal = settings.terminal.or(user_settings.terminal);
+        // Environment variable overrides, highest precedence.
+        resolver.apply_env();
 
-        Ok(Self {
-            port_count: settings.port_count,
-            port_range_start: settings.port_range_start,
-            port_range_end: settings.port_range_end,
-            branch_prefix: settings.branch_prefix,
-            auto_launch_terminal,
-            worktree_dir: local_settings.worktree_dir,
-            terminal,
-        })
+        Ok(resolver.finish())
     }
 
     /// Get the worktree directory for a project
@@ -328,22 +544,307 @@ impl MergedSettings {
     }
 }
 
+/// Accumulates settings values across the discovery chain, recording where each
+/// effective value came from. Sources are applied lowest-precedence first; each
+/// `apply_*` call overwrites only the fields it actually sets.
+struct SettingsResolver {
+    port_count: u16,
+    port_range_start: u16,
+    port_range_end: u16,
+    branch_prefix: String,
+    auto_launch_terminal: bool,
+    worktree_dir: Option<PathBuf>,
+    terminal: Option<String>,
+    special_paths: HashMap<String, crate::provision::ProvisionMode>,
+    exclude: Vec<String>,
+    shell: ShellConfig,
+    working_directory: WorkingDirectory,
+    verbs: Vec<VerbConf>,
+    user: Option<GitUser>,
+    init_submodules: bool,
+    provenance: ConfigProvenance,
+}
+
+impl Default for SettingsResolver {
+    fn default() -> Self {
+        let mut provenance = ConfigProvenance::default();
+        for field in [
+            "port_count",
+            "port_range_start",
+            "port_range_end",
+            "branch_prefix",
+            "auto_launch_terminal",
+            "worktree_dir",
+            "terminal",
+            "shell",
+            "working_directory",
+        ] {
+            provenance.set(field, SettingSource::Default);
+        }
+
+        Self {
+            port_count: default_port_count(),
+            port_range_start: default_port_range_start(),
+            port_range_end: default_port_range_end(),
+            branch_prefix: default_branch_prefix(),
+            auto_launch_terminal: true,
+            worktree_dir: None,
+            terminal: None,
+            special_paths: HashMap::new(),
+            exclude: Vec::new(),
+            shell: ShellConfig::default(),
+            working_directory: WorkingDirectory::default(),
+            verbs: Vec::new(),
+            user: None,
+            init_submodules: default_init_submodules(),
+            provenance,
+        }
+    }
+}
+
+impl SettingsResolver {
+    /// Merge the fields present in a single JSON file. Missing keys are left
+    /// untouched so partial files layer cleanly over lower-precedence sources.
+    fn apply_file(&mut self, path: &Path) -> Result<()> {
+        let mut ancestors = Vec::new();
+        self.apply_file_inner(path, &mut ancestors)
+    }
+
+    /// Apply `path`, tracking the chain of imports leading to it so a genuine
+    /// cycle (a file reappearing on its own ancestor chain) is rejected while
+    /// a diamond import (the same file reached twice via separate branches,
+    /// e.g. two team configs that both import a shared base) is not.
+    fn apply_file_inner(&mut self, path: &Path, ancestors: &mut Vec<PathBuf>) -> Result<()> {
+        // Track absolute paths to detect import cycles. Fall back to the given
+        // path if canonicalization fails (e.g. file does not exist yet).
+        let canonical = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        if ancestors.contains(&canonical) {
+            bail!("Import cycle detected at {}", path.display());
+        }
+        ancestors.push(canonical);
+        let result = self.apply_file_body(path, ancestors);
+        ancestors.pop();
+        result
+    }
+
+    fn apply_file_body(&mut self, path: &Path, ancestors: &mut Vec<PathBuf>) -> Result<()> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let value: serde_json::Value = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        let Some(object) = value.as_object() else {
+            return Ok(());
+        };
+        let source = SettingSource::File(path.to_path_buf());
+
+        // Imports are merged before this file's own fields, so the importing
+        // file overrides anything it pulls in. Relative paths resolve against
+        // the importing file's directory.
+        if let Some(imports) = object.get("imports").and_then(|v| v.as_array()) {
+            let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+            for import in imports.iter().filter_map(|v| v.as_str()) {
+                let import_path = base_dir.join(import);
+                if !import_path.exists() {
+                    bail!(
+                        "Imported settings file not found: {} (imported by {})",
+                        import_path.display(),
+                        path.display()
+                    );
+                }
+                self.apply_file_inner(&import_path, ancestors)?;
+            }
+        }
+
+        if let Some(v) = object.get("portCount").and_then(|v| v.as_u64()) {
+            self.port_count = v as u16;
+            self.provenance.set("port_count", source.clone());
+        }
+        if let Some(v) = object.get("portRangeStart").and_then(|v| v.as_u64()) {
+            self.port_range_start = v as u16;
+            self.provenance.set("port_range_start", source.clone());
+        }
+        if let Some(v) = object.get("portRangeEnd").and_then(|v| v.as_u64()) {
+            self.port_range_end = v as u16;
+            self.provenance.set("port_range_end", source.clone());
+        }
+        if let Some(v) = object.get("branchPrefix").and_then(|v| v.as_str()) {
+            self.branch_prefix = v.to_string();
+            self.provenance.set("branch_prefix", source.clone());
+        }
+        if let Some(v) = object.get("autoLaunchTerminal").and_then(|v| v.as_bool()) {
+            self.auto_launch_terminal = v;
+            self.provenance.set("auto_launch_terminal", source.clone());
+        }
+        if let Some(v) = object.get("worktreeDir").and_then(|v| v.as_str()) {
+            self.worktree_dir = Some(PathBuf::from(v));
+            self.provenance.set("worktree_dir", source.clone());
+        }
+        if let Some(v) = object.get("terminal").and_then(|v| v.as_str()) {
+            self.terminal = Some(v.to_string());
+            self.provenance.set("terminal", source.clone());
+        }
+        if let Some(v) = object.get("specialPaths") {
+            self.special_paths = serde_json::from_value(v.clone()).with_context(|| {
+                format!("Invalid specialPaths in {}", path.display())
+            })?;
+        }
+        if let Some(v) = object.get("exclude") {
+            self.exclude = serde_json::from_value(v.clone())
+                .with_context(|| format!("Invalid exclude in {}", path.display()))?;
+        }
+        if let Some(v) = object.get("shell") {
+            self.shell = serde_json::from_value(v.clone())
+                .with_context(|| format!("Invalid shell in {}", path.display()))?;
+            self.provenance.set("shell", source.clone());
+        }
+        if let Some(v) = object.get("workingDirectory") {
+            self.working_directory = serde_json::from_value(v.clone())
+                .with_context(|| format!("Invalid workingDirectory in {}", path.display()))?;
+            self.provenance.set("working_directory", source.clone());
+        }
+        if let Some(v) = object.get("verbs") {
+            self.verbs = serde_json::from_value(v.clone())
+                .with_context(|| format!("Invalid verbs in {}", path.display()))?;
+        }
+        if let Some(v) = object.get("user") {
+            self.user = serde_json::from_value(v.clone())
+                .with_context(|| format!("Invalid user in {}", path.display()))?;
+        }
+        if let Some(v) = object.get("initSubmodules").and_then(|v| v.as_bool()) {
+            self.init_submodules = v;
+        }
+
+        Ok(())
+    }
+
+    /// Apply environment variable overrides, the highest-precedence source.
+    fn apply_env(&mut self) {
+        if let Ok(v) = std::env::var("WORKTREE_PORT_COUNT") {
+            if let Ok(count) = v.parse() {
+                self.port_count = count;
+                self.provenance
+                    .set("port_count", SettingSource::Env("WORKTREE_PORT_COUNT".into()));
+            }
+        }
+        if let Ok(v) = std::env::var("WORKTREE_BRANCH_PREFIX") {
+            self.branch_prefix = v;
+            self.provenance.set(
+                "branch_prefix",
+                SettingSource::Env("WORKTREE_BRANCH_PREFIX".into()),
+            );
+        }
+        if let Ok(v) = std::env::var("WORKTREE_TERMINAL") {
+            self.terminal = Some(v);
+            self.provenance
+                .set("terminal", SettingSource::Env("WORKTREE_TERMINAL".into()));
+        }
+    }
+
+    fn finish(self) -> (MergedSettings, ConfigProvenance) {
+        (
+            MergedSettings {
+                port_count: self.port_count,
+                port_range_start: self.port_range_start,
+                port_range_end: self.port_range_end,
+                branch_prefix: self.branch_prefix,
+                auto_launch_terminal: self.auto_launch_terminal,
+                worktree_dir: self.worktree_dir,
+                terminal: self.terminal,
+                special_paths: self.special_paths,
+                exclude: self.exclude,
+                shell: self.shell,
+                working_directory: self.working_directory,
+                verbs: self.verbs,
+                user: self.user,
+                init_submodules: self.init_submodules,
+            },
+            self.provenance,
+        )
+    }
+}
+
+impl Settings {
+    /// Load the project settings file for `root`, falling back to defaults when
+    /// it is absent.
+    pub fn load_from(root: &Path) -> Result<Self> {
+        let path = paths::settings_file_in(root);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))
+    }
+}
+
+impl LocalSettings {
+    /// Load the local settings file for `root`, falling back to defaults when it
+    /// is absent.
+    pub fn load_from(root: &Path) -> Result<Self> {
+        let path = paths::local_settings_file_in(root);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))
+    }
+}
+
+/// If `path` already exists, copy it to `<path>.bak.<unixtime>` and return the
+/// backup location so callers can surface it for rollback.
+fn backup_existing(path: &Path) -> Result<Option<PathBuf>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let stamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let file_name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .context("Invalid settings file name")?;
+    let backup_path = path.with_file_name(format!("{}.bak.{}", file_name, stamp));
+
+    std::fs::copy(path, &backup_path).with_context(|| {
+        format!(
+            "Failed to back up {} to {}",
+            path.display(),
+            backup_path.display()
+        )
+    })?;
+
+    Ok(Some(backup_path))
+}
+
+/// Write `content` to `path`, backing up any existing file first and printing
+/// the backup location.
+fn write_with_backup(path: &Path, content: &str) -> Result<()> {
+    if let Some(backup) = backup_existing(path)? {
+        println!("  {} {}", "Backed up".yellow(), backup.display());
+    }
+    std::fs::write(path, content)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}
+
 /// Save settings to file
 pub fn save_settings(settings: &Settings, root: &Path) -> Result<()> {
     let settings_path = paths::settings_file_in(root);
     let content = serde_json::to_string_pretty(settings)?;
-    std::fs::write(&settings_path, content)
-        .with_context(|| format!("Failed to write {}", settings_path.display()))?;
-    Ok(())
+    write_with_backup(&settings_path, &content)
 }
 
 /// Save local settings to file
 pub fn save_local_settings(settings: &LocalSettings, root: &Path) -> Result<()> {
     let settings_path = paths::local_settings_file_in(root);
     let content = serde_json::to_string_pretty(settings)?;
-    std::fs::write(&settings_path, content)
-        .with_context(|| format!("Failed to write {}", settings_path.display()))?;
-    Ok(())
+    write_with_backup(&settings_path, &content)
 }
 
 #[cfg(test)]
@@ -426,4 +927,51 @@ mod tests {
         assert!(settings.auto_launch_terminal.is_none());
         assert!(settings.terminal.is_none());
     }
+
+    fn unique_test_dir(label: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "worktree-settings-test-{}-{}-{:?}",
+            label,
+            std::process::id(),
+            std::thread::current().id()
+        ))
+    }
+
+    #[test]
+    fn test_apply_file_allows_diamond_import() {
+        // settings.json imports both team-a.json and team-b.json, each of
+        // which imports the same common.json. That's not a cycle.
+        let dir = unique_test_dir("diamond");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        std::fs::write(dir.join("common.json"), r#"{"portCount": 7}"#).unwrap();
+        std::fs::write(dir.join("team-a.json"), r#"{"imports": ["common.json"]}"#).unwrap();
+        std::fs::write(dir.join("team-b.json"), r#"{"imports": ["common.json"]}"#).unwrap();
+        std::fs::write(
+            dir.join("settings.json"),
+            r#"{"imports": ["team-a.json", "team-b.json"]}"#,
+        )
+        .unwrap();
+
+        let mut resolver = SettingsResolver::default();
+        resolver.apply_file(&dir.join("settings.json")).unwrap();
+        assert_eq!(resolver.port_count, 7);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_apply_file_detects_real_cycle() {
+        let dir = unique_test_dir("cycle");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        std::fs::write(dir.join("a.json"), r#"{"imports": ["b.json"]}"#).unwrap();
+        std::fs::write(dir.join("b.json"), r#"{"imports": ["a.json"]}"#).unwrap();
+
+        let mut resolver = SettingsResolver::default();
+        let err = resolver.apply_file(&dir.join("a.json")).unwrap_err();
+        assert!(err.to_string().contains("Import cycle detected"));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
 }
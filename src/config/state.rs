@@ -2,6 +2,63 @@ use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// How long to wait for a process to exit after SIGTERM before escalating to
+/// SIGKILL.
+const GRACEFUL_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// SSH host a worktree lives on, for worktrees that run on a remote dev box.
+/// When present, terminals and port allocation target the remote machine.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteHost {
+    /// SSH destination, e.g. `user@host`.
+    pub host: String,
+
+    /// SSH port, if not the default 22.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+
+    /// Directory on the remote host; defaults to the worktree path when absent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+}
+
+impl RemoteHost {
+    /// The component used to namespace port allocations per host.
+    pub fn namespace(&self) -> &str {
+        &self.host
+    }
+}
+
+/// A long-lived process started by a run phase, tracked so it can be reliably
+/// stopped later instead of relying on fragile `pkill -f` patterns.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackedProcess {
+    /// Operating-system process id.
+    pub pid: u32,
+
+    /// Process-group id, when the process was started in its own group so the
+    /// whole tree of children can be signalled together.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pgid: Option<u32>,
+
+    /// Human-readable label, e.g. the command that was launched.
+    pub label: String,
+
+    /// When the process was recorded.
+    pub started_at: DateTime<Utc>,
+}
+
+/// Outcome of terminating a single tracked process, for per-process reporting.
+pub struct ProcessStopResult {
+    pub label: String,
+    pub pid: u32,
+    /// Whether the process was still running when termination was attempted.
+    pub was_running: bool,
+}
 
 /// Worktree state stored in state.json
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +79,19 @@ pub struct WorktreeState {
     /// Custom display name (optional, defaults to directory name)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub display_name: Option<String>,
+
+    /// Remote host this worktree runs on (optional; local when absent).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssh_host: Option<RemoteHost>,
+
+    /// Background processes started for this worktree, to be supervised and
+    /// stopped on close.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub processes: Vec<TrackedProcess>,
+
+    /// User-assigned tags for grouping worktrees across projects.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
 }
 
 /// Builder for creating WorktreeState
@@ -34,6 +104,7 @@ pub struct WorktreeStateBuilder {
     ports: Vec<u16>,
     param: Option<String>,
     display_name: Option<String>,
+    ssh_host: Option<RemoteHost>,
 }
 
 impl WorktreeStateBuilder {
@@ -48,6 +119,7 @@ impl WorktreeStateBuilder {
             ports: Vec::new(),
             param: None,
             display_name: None,
+            ssh_host: None,
         }
     }
 
@@ -76,8 +148,18 @@ impl WorktreeStateBuilder {
         self
     }
 
+    pub fn ssh_host(mut self, ssh_host: Option<RemoteHost>) -> Self {
+        self.ssh_host = ssh_host;
+        self
+    }
+
     pub fn build(self) -> WorktreeState {
-        let allocation_key = format!("{}/{}", self.project_name, self.name);
+        // Namespace the allocation key by host so ports on different machines
+        // don't collide (5000 on two boxes are independent allocations).
+        let allocation_key = match &self.ssh_host {
+            Some(remote) => format!("{}/{}/{}", remote.namespace(), self.project_name, self.name),
+            None => format!("{}/{}", self.project_name, self.name),
+        };
         WorktreeState {
             name: self.name,
             project_name: self.project_name,
@@ -89,6 +171,9 @@ impl WorktreeStateBuilder {
             created_at: Utc::now(),
             param: self.param,
             display_name: self.display_name,
+            ssh_host: self.ssh_host,
+            processes: Vec::new(),
+            tags: Vec::new(),
         }
     }
 }
@@ -121,6 +206,11 @@ impl WorktreeState {
             || self.allocation_key.ends_with(&format!("/{}", identifier))
     }
 
+    /// Whether this worktree carries `tag`.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
     /// Save state to the worktree directory
     pub fn save(&self) -> Result<()> {
         let state_path = self.worktree_dir.join("state.json");
@@ -130,6 +220,78 @@ impl WorktreeState {
         Ok(())
     }
 
+    /// Record a background process and persist the updated state. `pgid` is the
+    /// process-group id when the process was started in its own group.
+    pub fn record_process(
+        &mut self,
+        pid: u32,
+        pgid: Option<u32>,
+        label: impl Into<String>,
+    ) -> Result<()> {
+        self.processes.push(TrackedProcess {
+            pid,
+            pgid,
+            label: label.into(),
+            started_at: Utc::now(),
+        });
+        self.save()
+    }
+
+    /// The tracked processes that are still alive, dropping any that have
+    /// already exited.
+    pub fn running_processes(&self) -> Vec<&TrackedProcess> {
+        self.processes
+            .iter()
+            .filter(|p| process_is_alive(p.pid))
+            .collect()
+    }
+
+    /// Stop every tracked process. When `graceful` is set each live process is
+    /// sent SIGTERM first and given [`GRACEFUL_TIMEOUT`] to exit before being
+    /// force-killed; otherwise it is killed immediately. When a process was
+    /// recorded with a process-group id the whole group is signalled so child
+    /// servers don't survive their parent. Dead entries are pruned from the
+    /// persisted state when the worktree still exists, and a per-process result
+    /// is returned for reporting.
+    pub fn stop_tracked_processes(&self, graceful: bool) -> Result<Vec<ProcessStopResult>> {
+        let mut results = Vec::new();
+        if self.processes.is_empty() {
+            return Ok(results);
+        }
+
+        for proc in &self.processes {
+            let was_running = process_is_alive(proc.pid);
+            results.push(ProcessStopResult {
+                label: proc.label.clone(),
+                pid: proc.pid,
+                was_running,
+            });
+            if !was_running {
+                continue;
+            }
+
+            // Signal the whole process group when known, else just the pid.
+            let target = Target::new(proc.pid, proc.pgid);
+            if graceful {
+                send_signal(target, Signal::Term);
+                if wait_for_exit(proc.pid, GRACEFUL_TIMEOUT) {
+                    continue;
+                }
+            }
+
+            send_signal(target, Signal::Kill);
+        }
+
+        // Persist the pruned list (dead PIDs dropped) while the worktree lives.
+        if self.worktree_dir.exists() {
+            let mut pruned = self.clone();
+            pruned.processes.retain(|p| process_is_alive(p.pid));
+            let _ = pruned.save();
+        }
+
+        Ok(results)
+    }
+
     /// Load state from a state.json file
     pub fn load(path: &Path) -> Result<Self> {
         let content = std::fs::read_to_string(path)
@@ -140,6 +302,87 @@ impl WorktreeState {
     }
 }
 
+/// A termination signal, mapped to the platform's process-control tool.
+enum Signal {
+    Term,
+    Kill,
+}
+
+/// What a signal is delivered to: a whole process group when a pgid is known,
+/// otherwise a single pid.
+struct Target {
+    pid: u32,
+    pgid: Option<u32>,
+}
+
+impl Target {
+    fn new(pid: u32, pgid: Option<u32>) -> Self {
+        Self { pid, pgid }
+    }
+}
+
+/// Whether a process with `pid` is currently alive.
+#[cfg(unix)]
+fn process_is_alive(pid: u32) -> bool {
+    // `kill -0` performs permission/existence checks without delivering a signal.
+    crate::process::create_command("kill")
+        .args(["-0", &pid.to_string()])
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+#[cfg(windows)]
+fn process_is_alive(pid: u32) -> bool {
+    crate::process::create_command("tasklist")
+        .args(["/FI", &format!("PID eq {}", pid)])
+        .output()
+        .map(|o| String::from_utf8_lossy(&o.stdout).contains(&pid.to_string()))
+        .unwrap_or(false)
+}
+
+#[cfg(unix)]
+fn send_signal(target: Target, signal: Signal) {
+    let flag = match signal {
+        Signal::Term => "-TERM",
+        Signal::Kill => "-KILL",
+    };
+    // A negative pid tells `kill` to signal the whole process group.
+    let spec = match target.pgid {
+        Some(pgid) => format!("-{}", pgid),
+        None => target.pid.to_string(),
+    };
+    let _ = crate::process::create_command("kill")
+        .args([flag, &spec])
+        .status();
+}
+
+#[cfg(windows)]
+fn send_signal(target: Target, signal: Signal) {
+    // Windows has no SIGTERM/SIGKILL distinction; `/F` forces termination and
+    // `/T` also terminates the child process tree.
+    let mut cmd = crate::process::create_command("taskkill");
+    cmd.args(["/PID", &target.pid.to_string(), "/T"]);
+    if matches!(signal, Signal::Kill) {
+        cmd.arg("/F");
+    }
+    let _ = cmd.status();
+}
+
+/// Poll for a process to exit, up to `timeout`. Returns `true` once it is gone.
+fn wait_for_exit(pid: u32, timeout: Duration) -> bool {
+    let step = Duration::from_millis(100);
+    let mut waited = Duration::ZERO;
+    while waited < timeout {
+        if !process_is_alive(pid) {
+            return true;
+        }
+        std::thread::sleep(step);
+        waited += step;
+    }
+    !process_is_alive(pid)
+}
+
 /// Detect if the current directory is within a worktree by traversing up
 pub fn detect_worktree() -> Result<Option<WorktreeState>> {
     detect_worktree_from(&std::env::current_dir()?)
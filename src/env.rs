@@ -0,0 +1,172 @@
+//! Sanitize the environment handed to launched terminals.
+//!
+//! When this binary is distributed as (or launched from) a Flatpak, Snap, or
+//! AppImage, the runtime injects variables such as `LD_LIBRARY_PATH`,
+//! `GST_PLUGIN_*`, `PYTHONPATH`, and rewritten `PATH`/`XDG_DATA_DIRS` that point
+//! into the sandbox. Left untouched, they leak into the terminal we spawn and
+//! break the tools run inside the worktree. This module detects the sandbox,
+//! strips the injected entries, and (on Linux) opens the terminal on the real
+//! host via `flatpak-spawn --host`.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Path-list variables the packaging runtimes commonly rewrite.
+const INJECTED_VARS: &[&str] = &[
+    "LD_LIBRARY_PATH",
+    "GST_PLUGIN_PATH",
+    "GST_PLUGIN_PATH_1_0",
+    "GST_PLUGIN_SYSTEM_PATH",
+    "GST_PLUGIN_SYSTEM_PATH_1_0",
+    "PYTHONPATH",
+    "PATH",
+    "XDG_DATA_DIRS",
+];
+
+/// The kind of sandbox/packaging the current process is running under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SandboxKind {
+    Flatpak,
+    Snap,
+    AppImage,
+}
+
+/// A detected sandbox plus the filesystem roots whose entries must be dropped
+/// from inherited path lists.
+#[derive(Debug, Clone)]
+pub struct Sandbox {
+    pub kind: SandboxKind,
+    pub roots: Vec<PathBuf>,
+}
+
+/// Detect the current sandbox, if any.
+pub fn detect() -> Option<Sandbox> {
+    if Path::new("/.flatpak-info").exists() {
+        return Some(Sandbox {
+            kind: SandboxKind::Flatpak,
+            roots: vec![PathBuf::from("/app")],
+        });
+    }
+
+    if let Some(root) = std::env::var_os("SNAP").filter(|v| !v.is_empty()) {
+        return Some(Sandbox {
+            kind: SandboxKind::Snap,
+            roots: vec![PathBuf::from(root)],
+        });
+    }
+    if std::env::var_os("SNAP_NAME").is_some() {
+        return Some(Sandbox {
+            kind: SandboxKind::Snap,
+            roots: Vec::new(),
+        });
+    }
+
+    if let Some(root) = std::env::var_os("APPDIR").filter(|v| !v.is_empty()) {
+        return Some(Sandbox {
+            kind: SandboxKind::AppImage,
+            roots: vec![PathBuf::from(root)],
+        });
+    }
+    if std::env::var_os("APPIMAGE").is_some() {
+        return Some(Sandbox {
+            kind: SandboxKind::AppImage,
+            roots: Vec::new(),
+        });
+    }
+
+    None
+}
+
+/// Normalize a `:`-separated path list: drop entries that fall under any of the
+/// injected roots, de-duplicate while preserving first-seen order, and return
+/// `None` when nothing remains (signalling the variable should be unset).
+pub fn normalize_pathlist(value: &str, injected_prefixes: &[PathBuf]) -> Option<String> {
+    let mut seen = HashSet::new();
+    let kept: Vec<&str> = value
+        .split(':')
+        .filter(|entry| !entry.is_empty())
+        .filter(|entry| {
+            !injected_prefixes
+                .iter()
+                .any(|prefix| Path::new(entry).starts_with(prefix))
+        })
+        .filter(|entry| seen.insert(*entry))
+        .collect();
+
+    if kept.is_empty() {
+        None
+    } else {
+        Some(kept.join(":"))
+    }
+}
+
+/// Build a `Command` for `program`, sanitizing injected path variables and, when
+/// a sandbox is detected on Linux, routing through `flatpak-spawn --host` so the
+/// terminal opens on the real host. Callers append their own arguments.
+pub fn prepare_command(program: &str) -> Command {
+    let sandbox = detect();
+
+    // Compute the cleaned value for each injected variable from our own env.
+    let cleaned: Vec<(String, Option<String>)> = match &sandbox {
+        Some(sandbox) => INJECTED_VARS
+            .iter()
+            .filter_map(|var| {
+                std::env::var(var)
+                    .ok()
+                    .map(|value| (var.to_string(), normalize_pathlist(&value, &sandbox.roots)))
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+
+    // On Linux, a detected sandbox means we must escape it to reach the host.
+    #[cfg(target_os = "linux")]
+    if sandbox.is_some() {
+        let mut command = crate::process::create_command("flatpak-spawn");
+        command.arg("--host");
+        for (var, value) in &cleaned {
+            match value {
+                Some(value) => command.arg(format!("--env={}={}", var, value)),
+                None => command.arg(format!("--unset-env={}", var)),
+            };
+        }
+        command.arg(program);
+        return command;
+    }
+
+    // Otherwise run the program directly, stripping injected variables in place.
+    let mut command = crate::process::create_command(program);
+    for (var, value) in &cleaned {
+        match value {
+            Some(value) => command.env(var, value),
+            None => command.env_remove(var),
+        };
+    }
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_pathlist_drops_sandbox_entries() {
+        let roots = vec![PathBuf::from("/app"), PathBuf::from("/snap")];
+        let result = normalize_pathlist("/app/lib:/usr/lib:/snap/x/lib", &roots);
+        assert_eq!(result, Some("/usr/lib".to_string()));
+    }
+
+    #[test]
+    fn test_normalize_pathlist_dedups_preserving_order() {
+        let result = normalize_pathlist("/a:/b:/a:/c:/b", &[]);
+        assert_eq!(result, Some("/a:/b:/c".to_string()));
+    }
+
+    #[test]
+    fn test_normalize_pathlist_empty_result_is_none() {
+        let roots = vec![PathBuf::from("/app")];
+        assert_eq!(normalize_pathlist("/app/bin:/app/lib", &roots), None);
+        assert_eq!(normalize_pathlist("", &roots), None);
+    }
+}
@@ -0,0 +1,220 @@
+//! Pluggable Git backend.
+//!
+//! Every git query in this crate historically shelled out to a fresh `git`
+//! process. That is fine for a one-off lookup but expensive when
+//! `find_all_worktrees` iterates dozens of state files and wants a commit date
+//! for each — a listing of N worktrees costs on the order of 3N subprocess
+//! launches.
+//!
+//! [`GitBackend`] abstracts the operations the crate actually uses so an
+//! in-process implementation can open the repository once and reuse the
+//! handle. Two implementations are provided: [`CommandBackend`], the default
+//! that spawns `git`, and (behind the `git2` feature) `Git2Backend`, which
+//! keeps a [`git2::Repository`] open for the lifetime of the backend.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::path::{Path, PathBuf};
+
+/// The Git operations the crate depends on.
+pub trait GitBackend {
+    /// Whether the current directory is inside a git repository.
+    fn is_git_repo(&self) -> bool;
+
+    /// The root of the current repository or worktree.
+    fn repo_root(&self) -> Result<PathBuf>;
+
+    /// The root of the main working tree, even when called from a worktree.
+    fn main_repo_root(&self) -> Result<PathBuf>;
+
+    /// Whether a branch exists locally.
+    fn branch_exists(&self, branch: &str) -> bool;
+
+    /// Every worktree registered with the repository.
+    fn list_worktrees(&self) -> Result<Vec<super::GitWorktree>>;
+
+    /// The date of the most recent commit reachable from `dir`'s HEAD.
+    fn latest_commit_date(&self, dir: &Path) -> Result<DateTime<Utc>>;
+
+    /// Create a worktree at `path` on a new `branch`.
+    fn create_worktree(&self, path: &Path, branch: &str) -> Result<()>;
+
+    /// Remove the worktree at `worktree_dir`.
+    fn remove_worktree(&self, original_dir: &Path, worktree_dir: &Path, force: bool) -> Result<()>;
+}
+
+/// Backend that shells out to the `git` executable for every call.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CommandBackend;
+
+impl GitBackend for CommandBackend {
+    fn is_git_repo(&self) -> bool {
+        super::is_git_repo()
+    }
+
+    fn repo_root(&self) -> Result<PathBuf> {
+        super::get_repo_root()
+    }
+
+    fn main_repo_root(&self) -> Result<PathBuf> {
+        super::get_main_repo_root()
+    }
+
+    fn branch_exists(&self, branch: &str) -> bool {
+        super::branch_exists(branch)
+    }
+
+    fn list_worktrees(&self) -> Result<Vec<super::GitWorktree>> {
+        super::list_git_worktrees()
+    }
+
+    fn latest_commit_date(&self, dir: &Path) -> Result<DateTime<Utc>> {
+        super::get_latest_commit_date(dir)
+    }
+
+    fn create_worktree(&self, path: &Path, branch: &str) -> Result<()> {
+        super::create_worktree(path, branch)
+    }
+
+    fn remove_worktree(&self, original_dir: &Path, worktree_dir: &Path, force: bool) -> Result<()> {
+        super::remove_worktree(original_dir, worktree_dir, force)
+    }
+}
+
+/// Select a backend from the `WORKTREE_GIT_BACKEND` environment variable,
+/// defaulting to [`CommandBackend`]. Recognised values are `command` and, when
+/// the `git2` feature is enabled, `git2`.
+pub fn select_backend() -> Box<dyn GitBackend> {
+    match std::env::var("WORKTREE_GIT_BACKEND").ok().as_deref() {
+        #[cfg(feature = "git2")]
+        Some("git2") => match git2_impl::Git2Backend::discover() {
+            Ok(backend) => Box::new(backend),
+            // Fall back to the command backend if the repo can't be opened.
+            Err(_) => Box::new(CommandBackend),
+        },
+        _ => Box::new(CommandBackend),
+    }
+}
+
+#[cfg(feature = "git2")]
+mod git2_impl {
+    use super::*;
+    use anyhow::Context;
+
+    /// In-process backend that opens the repository once and reuses the handle.
+    pub struct Git2Backend {
+        repo: git2::Repository,
+    }
+
+    impl Git2Backend {
+        /// Open the repository containing the current directory.
+        pub fn discover() -> Result<Self> {
+            let repo = git2::Repository::discover(".")
+                .context("Failed to open git repository in-process")?;
+            Ok(Self { repo })
+        }
+
+        /// Find the linked worktree (if any) whose path is `dir`, by consulting
+        /// `self.repo`'s own worktree list rather than opening `dir` blind.
+        /// Returns `None` when `dir` is the main working tree *or* belongs to a
+        /// repository other than the one this backend was opened against.
+        fn linked_worktree_named(&self, dir: &Path) -> Result<Option<git2::Worktree>> {
+            let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+            for name in self.repo.worktrees()?.iter().flatten() {
+                let worktree = self.repo.find_worktree(name)?;
+                let path = worktree
+                    .path()
+                    .canonicalize()
+                    .unwrap_or_else(|_| worktree.path().to_path_buf());
+                if path == dir {
+                    return Ok(Some(worktree));
+                }
+            }
+            Ok(None)
+        }
+
+        /// Whether `dir` is the main working tree of `self.repo`, as opposed to
+        /// a linked worktree of it (checked separately) or a directory
+        /// belonging to an entirely different repository.
+        fn is_main_working_tree(&self, dir: &Path) -> bool {
+            let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+            self.repo
+                .workdir()
+                .map(|workdir| workdir.canonicalize().unwrap_or_else(|_| workdir.to_path_buf()))
+                .is_some_and(|workdir| workdir == dir)
+        }
+    }
+
+    impl GitBackend for Git2Backend {
+        fn is_git_repo(&self) -> bool {
+            true
+        }
+
+        fn repo_root(&self) -> Result<PathBuf> {
+            self.repo
+                .workdir()
+                .map(|p| p.to_path_buf())
+                .context("Repository has no working directory")
+        }
+
+        fn main_repo_root(&self) -> Result<PathBuf> {
+            // `commondir` points at the main repo's `.git`; its parent is the
+            // main working tree.
+            let common = self.repo.commondir();
+            common
+                .parent()
+                .map(|p| p.to_path_buf())
+                .context("Could not resolve main repository root")
+        }
+
+        fn branch_exists(&self, branch: &str) -> bool {
+            self.repo
+                .find_branch(branch, git2::BranchType::Local)
+                .is_ok()
+        }
+
+        fn list_worktrees(&self) -> Result<Vec<super::super::GitWorktree>> {
+            // Reuse the porcelain parser; git2's worktree view omits the main
+            // working tree, which callers expect first.
+            super::super::list_git_worktrees()
+        }
+
+        fn latest_commit_date(&self, dir: &Path) -> Result<DateTime<Utc>> {
+            let seconds = match self.linked_worktree_named(dir)? {
+                // `dir` is a linked worktree: locate it off the already-open
+                // main repository instead of rediscovering it from its path.
+                Some(worktree) => {
+                    let repo = git2::Repository::open_from_worktree(&worktree)?;
+                    let commit = repo.head()?.peel_to_commit()?;
+                    commit.time().seconds()
+                }
+                // `dir` is the main working tree, which `self.repo` already is.
+                None if self.is_main_working_tree(dir) => {
+                    self.repo.head()?.peel_to_commit()?.time().seconds()
+                }
+                // `dir` belongs to neither `self.repo` nor one of its linked
+                // worktrees — e.g. `cleanup --all` scanning worktrees across
+                // every project while this backend was opened against just
+                // one of them. Fall back to the subprocess backend for this
+                // single lookup rather than silently reporting `self.repo`'s
+                // HEAD as if it were `dir`'s.
+                None => return super::CommandBackend.latest_commit_date(dir),
+            };
+            DateTime::from_timestamp(seconds, 0).context("Invalid commit timestamp")
+        }
+
+        fn create_worktree(&self, path: &Path, branch: &str) -> Result<()> {
+            // Worktree creation with a new branch is still clearest via the CLI.
+            super::super::create_worktree(path, branch)
+        }
+
+        fn remove_worktree(
+            &self,
+            original_dir: &Path,
+            worktree_dir: &Path,
+            force: bool,
+        ) -> Result<()> {
+            super::super::remove_worktree(original_dir, worktree_dir, force)
+        }
+    }
+}
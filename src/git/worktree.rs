@@ -1,11 +1,12 @@
 use anyhow::{bail, Context, Result};
 use chrono::{DateTime, Utc};
+use colored::Colorize;
+use serde::Serialize;
 use std::path::{Path, PathBuf};
-use std::process::Command;
 
 /// Check if the current directory is inside a git repository
 pub fn is_git_repo() -> bool {
-    Command::new("git")
+    crate::process::create_command("git")
         .args(["rev-parse", "--git-dir"])
         .output()
         .map(|o| o.status.success())
@@ -14,7 +15,7 @@ pub fn is_git_repo() -> bool {
 
 /// Get the root directory of the current git repository (or worktree)
 pub fn get_repo_root() -> Result<PathBuf> {
-    let output = Command::new("git")
+    let output = crate::process::create_command("git")
         .args(["rev-parse", "--show-toplevel"])
         .output()
         .context("Failed to execute git rev-parse")?;
@@ -39,7 +40,7 @@ pub fn get_main_repo_root() -> Result<PathBuf> {
     // /path/to/main/repo  abc1234 [main]
     // /path/to/worktree   def5678 [feature-branch]
     // The first entry is always the main working tree
-    let output = Command::new("git")
+    let output = crate::process::create_command("git")
         .args(["worktree", "list", "--porcelain"])
         .output()
         .context("Failed to execute git worktree list")?;
@@ -62,6 +63,189 @@ pub fn get_main_repo_root() -> Result<PathBuf> {
     get_repo_root()
 }
 
+/// A worktree as reported by `git worktree list --porcelain`.
+#[derive(Debug, Clone, Default)]
+pub struct GitWorktree {
+    pub path: PathBuf,
+    pub head: Option<String>,
+    pub branch: Option<String>,
+    pub bare: bool,
+    pub detached: bool,
+    pub locked: Option<String>,
+    pub prunable: Option<String>,
+}
+
+/// Parse every stanza of `git worktree list --porcelain` into structured
+/// records. Each record is a blank-line-separated block.
+pub fn list_git_worktrees() -> Result<Vec<GitWorktree>> {
+    let output = crate::process::create_command("git")
+        .args(["worktree", "list", "--porcelain"])
+        .output()
+        .context("Failed to execute git worktree list")?;
+
+    if !output.status.success() {
+        bail!("git worktree list failed");
+    }
+
+    let stdout = String::from_utf8(output.stdout).context("Invalid UTF-8 in git output")?;
+
+    let mut worktrees = Vec::new();
+    let mut current: Option<GitWorktree> = None;
+
+    for line in stdout.lines() {
+        if line.is_empty() {
+            // Blank line terminates the current stanza.
+            if let Some(worktree) = current.take() {
+                worktrees.push(worktree);
+            }
+            continue;
+        }
+
+        let (key, value) = match line.split_once(' ') {
+            Some((key, value)) => (key, value),
+            None => (line, ""),
+        };
+
+        match key {
+            "worktree" => {
+                // A new stanza begins; flush any in-progress record first.
+                if let Some(worktree) = current.take() {
+                    worktrees.push(worktree);
+                }
+                current = Some(GitWorktree {
+                    path: PathBuf::from(value),
+                    ..Default::default()
+                });
+            }
+            _ => {
+                if let Some(worktree) = current.as_mut() {
+                    match key {
+                        "HEAD" => worktree.head = Some(value.to_string()),
+                        "branch" => {
+                            worktree.branch = Some(
+                                value
+                                    .strip_prefix("refs/heads/")
+                                    .unwrap_or(value)
+                                    .to_string(),
+                            );
+                        }
+                        "bare" => worktree.bare = true,
+                        "detached" => worktree.detached = true,
+                        "locked" => worktree.locked = Some(value.to_string()),
+                        "prunable" => worktree.prunable = Some(value.to_string()),
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+
+    // Flush the final stanza if the output didn't end with a blank line.
+    if let Some(worktree) = current.take() {
+        worktrees.push(worktree);
+    }
+
+    Ok(worktrees)
+}
+
+/// Working-tree status of a single worktree: changed/untracked file counts and
+/// how far the branch is ahead/behind its upstream.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct WorktreeStatus {
+    /// Tracked files with staged or unstaged changes.
+    pub dirty: usize,
+    /// Untracked files.
+    pub untracked: usize,
+    pub ahead: u32,
+    pub behind: u32,
+}
+
+impl WorktreeStatus {
+    /// Whether there is anything noteworthy to show.
+    pub fn is_clean(&self) -> bool {
+        self.dirty == 0 && self.untracked == 0 && self.ahead == 0 && self.behind == 0
+    }
+
+    /// Render the status as compact coloured markers, e.g. `✎3 ?2 ↑1↓0`.
+    /// Returns an empty string when the worktree is clean.
+    pub fn markers(&self) -> String {
+        if self.is_clean() {
+            return String::new();
+        }
+
+        let mut parts = Vec::new();
+        if self.dirty > 0 {
+            parts.push(format!("✎{}", self.dirty).yellow().to_string());
+        }
+        if self.untracked > 0 {
+            parts.push(format!("?{}", self.untracked).red().to_string());
+        }
+        if self.ahead > 0 || self.behind > 0 {
+            parts.push(
+                format!("↑{}↓{}", self.ahead, self.behind)
+                    .cyan()
+                    .to_string(),
+            );
+        }
+        parts.join(" ")
+    }
+}
+
+/// Collect the working-tree status for `dir` via `git status --porcelain=v2`.
+pub fn get_worktree_status(dir: &Path) -> Result<WorktreeStatus> {
+    let output = crate::process::create_command("git")
+        .args(["status", "--porcelain=v2", "--branch"])
+        .current_dir(dir)
+        .output()
+        .context("Failed to execute git status")?;
+
+    if !output.status.success() {
+        bail!("git status failed in {}", dir.display());
+    }
+
+    let stdout = String::from_utf8(output.stdout).context("Invalid UTF-8 in git output")?;
+
+    let mut status = WorktreeStatus::default();
+    for line in stdout.lines() {
+        if let Some(ab) = line.strip_prefix("# branch.ab ") {
+            // Format: "+<ahead> -<behind>"
+            for token in ab.split_whitespace() {
+                if let Some(ahead) = token.strip_prefix('+') {
+                    status.ahead = ahead.parse().unwrap_or(0);
+                } else if let Some(behind) = token.strip_prefix('-') {
+                    status.behind = behind.parse().unwrap_or(0);
+                }
+            }
+        } else if line.starts_with('?') {
+            // Untracked entry.
+            status.untracked += 1;
+        } else if line.starts_with('1') || line.starts_with('2') || line.starts_with('u') {
+            // Ordinary change, rename/copy, or unmerged entry.
+            status.dirty += 1;
+        }
+    }
+
+    Ok(status)
+}
+
+/// Get the abbreviated HEAD commit sha for a worktree directory.
+pub fn get_head_sha(dir: &Path) -> Result<String> {
+    let output = crate::process::create_command("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .current_dir(dir)
+        .output()
+        .context("Failed to execute git rev-parse")?;
+
+    if !output.status.success() {
+        bail!("git rev-parse failed in {}", dir.display());
+    }
+
+    Ok(String::from_utf8(output.stdout)
+        .context("Invalid UTF-8 in git output")?
+        .trim()
+        .to_string())
+}
+
 /// Get the project name from the main repository root directory name
 pub fn get_main_project_name() -> Result<String> {
     let root = get_main_repo_root()?;
@@ -75,18 +259,62 @@ pub fn get_main_project_name() -> Result<String> {
 
 /// Check if a branch exists locally
 pub fn branch_exists(branch: &str) -> bool {
-    Command::new("git")
+    crate::process::create_command("git")
         .args(["rev-parse", "--verify", branch])
         .output()
         .map(|o| o.status.success())
         .unwrap_or(false)
 }
 
-/// Create a new git worktree
+/// Check if a local branch exists (`refs/heads/<branch>`).
+pub fn local_branch_exists(branch: &str) -> bool {
+    crate::process::create_command("git")
+        .args([
+            "show-ref",
+            "--verify",
+            "--quiet",
+            &format!("refs/heads/{}", branch),
+        ])
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+/// Check if a remote-tracking branch exists (`refs/remotes/<reference>`), e.g.
+/// `origin/feature/x`.
+pub fn remote_branch_exists(reference: &str) -> bool {
+    crate::process::create_command("git")
+        .args([
+            "show-ref",
+            "--verify",
+            "--quiet",
+            &format!("refs/remotes/{}", reference),
+        ])
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+/// Fetch `refspec` from `remote` so a remote branch can be checked out.
+pub fn fetch(remote: &str, refspec: &str) -> Result<()> {
+    let output = crate::process::create_command("git")
+        .args(["fetch", remote, refspec])
+        .output()
+        .context("Failed to execute git fetch")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("git fetch failed: {}", stderr);
+    }
+
+    Ok(())
+}
+
+/// Create a new git worktree on a freshly created `branch`.
 pub fn create_worktree(path: &Path, branch: &str) -> Result<()> {
     let path_str = path.to_str().context("Invalid path for worktree")?;
 
-    let output = Command::new("git")
+    let output = crate::process::create_command("git")
         .args(["worktree", "add", path_str, "-b", branch])
         .output()
         .context("Failed to execute git worktree add")?;
@@ -99,6 +327,111 @@ pub fn create_worktree(path: &Path, branch: &str) -> Result<()> {
     Ok(())
 }
 
+/// Create a worktree on a new local `branch` based on `base_ref`. When
+/// `base_ref` is a remote-tracking branch, git sets up tracking automatically.
+pub fn create_worktree_from(path: &Path, branch: &str, base_ref: &str) -> Result<()> {
+    let path_str = path.to_str().context("Invalid path for worktree")?;
+
+    let output = crate::process::create_command("git")
+        .args(["worktree", "add", path_str, "-b", branch, base_ref])
+        .output()
+        .context("Failed to execute git worktree add")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("git worktree add failed: {}", stderr);
+    }
+
+    Ok(())
+}
+
+/// Attach a worktree to an `branch` that already exists locally.
+pub fn add_worktree_existing(path: &Path, branch: &str) -> Result<()> {
+    let path_str = path.to_str().context("Invalid path for worktree")?;
+
+    let output = crate::process::create_command("git")
+        .args(["worktree", "add", path_str, branch])
+        .output()
+        .context("Failed to execute git worktree add")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("git worktree add failed: {}", stderr);
+    }
+
+    Ok(())
+}
+
+/// Initialize and update all submodules inside a freshly created worktree.
+/// Worktrees share the main repository's `.git`, but submodule working trees
+/// are per-worktree and start empty, so this must run before setup scripts
+/// that expect vendored code to be present.
+pub fn init_submodules(worktree_dir: &Path) -> Result<()> {
+    let output = crate::process::create_command("git")
+        .args(["submodule", "update", "--init", "--recursive"])
+        .current_dir(worktree_dir)
+        .output()
+        .context("Failed to execute git submodule update")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("git submodule update failed: {}", stderr);
+    }
+
+    Ok(())
+}
+
+/// Whether the repository rooted at `worktree_dir` declares any submodules.
+pub fn has_submodules(worktree_dir: &Path) -> bool {
+    worktree_dir.join(".gitmodules").exists()
+}
+
+/// Add a subtree at `prefix` pulling `reference` from `repository`. Uses
+/// `--squash` so the worktree records a single vendored commit rather than the
+/// full upstream history, matching how `.gitsubtrees` tooling tracks deps.
+pub fn add_subtree(
+    worktree_dir: &Path,
+    prefix: &str,
+    repository: &str,
+    reference: &str,
+) -> Result<()> {
+    let output = crate::process::create_command("git")
+        .args([
+            "subtree",
+            "add",
+            &format!("--prefix={}", prefix),
+            repository,
+            reference,
+            "--squash",
+        ])
+        .current_dir(worktree_dir)
+        .output()
+        .context("Failed to execute git subtree add")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("git subtree add failed: {}", stderr);
+    }
+
+    Ok(())
+}
+
+/// Set a local git config value inside a worktree directory
+pub fn set_config(worktree_dir: &Path, key: &str, value: &str) -> Result<()> {
+    let output = crate::process::create_command("git")
+        .args(["config", key, value])
+        .current_dir(worktree_dir)
+        .output()
+        .with_context(|| format!("Failed to set git config {}", key))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("git config {} failed: {}", key, stderr);
+    }
+
+    Ok(())
+}
+
 /// Remove a git worktree
 pub fn remove_worktree(original_dir: &Path, worktree_dir: &Path, force: bool) -> Result<()> {
     let worktree_str = worktree_dir.to_str().context("Invalid worktree path")?;
@@ -108,7 +441,7 @@ pub fn remove_worktree(original_dir: &Path, worktree_dir: &Path, force: bool) ->
         args.push("--force");
     }
 
-    let output = Command::new("git")
+    let output = crate::process::create_command("git")
         .args(&args)
         .current_dir(original_dir)
         .output()
@@ -127,7 +460,7 @@ pub fn remove_worktree(original_dir: &Path, worktree_dir: &Path, force: bool) ->
     }
 
     // Prune stale worktree entries
-    let _ = Command::new("git")
+    let _ = crate::process::create_command("git")
         .args(["worktree", "prune"])
         .current_dir(original_dir)
         .output();
@@ -137,7 +470,7 @@ pub fn remove_worktree(original_dir: &Path, worktree_dir: &Path, force: bool) ->
 
 /// Get the latest commit date in a worktree directory
 pub fn get_latest_commit_date(worktree_dir: &Path) -> Result<DateTime<Utc>> {
-    let output = Command::new("git")
+    let output = crate::process::create_command("git")
         .args(["log", "-1", "--format=%aI"])
         .current_dir(worktree_dir)
         .output()
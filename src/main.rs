@@ -2,9 +2,12 @@ mod cli;
 mod commands;
 mod completions;
 mod config;
+mod env;
 mod git;
 mod names;
 mod ports;
+mod process;
+mod provision;
 mod scripts;
 mod terminal;
 
@@ -20,6 +23,11 @@ fn main() -> Result<()> {
 
     let cli = Cli::parse();
 
+    // Print resolved configuration and exit, regardless of subcommand
+    if cli.print_config {
+        return print_config();
+    }
+
     // Ensure user configuration exists for all commands except completions and help
     // This prompts for first-time setup if ~/.config/worktree/config.json doesn't exist
     if let Some(ref cmd) = cli.command {
@@ -32,28 +40,49 @@ fn main() -> Result<()> {
         Some(Commands::Init {
             defaults,
             no_scripts,
-        }) => commands::init::execute(defaults, no_scripts),
-        Some(Commands::New { param }) => commands::new::execute(param),
-        Some(Commands::Run) => commands::run::execute(),
+            reconfigure,
+        }) => commands::init::execute(defaults, no_scripts, reconfigure),
+        Some(Commands::New {
+            param,
+            branch,
+            from,
+            ssh_host,
+            ssh_port,
+            remote_path,
+        }) => commands::new::execute(param, branch, from, ssh_host, ssh_port, remote_path),
+        Some(Commands::Run { verb, background }) => commands::run::execute(verb, background),
         Some(Commands::Stop) => commands::stop::execute(),
         Some(Commands::Close {
             name,
             force,
             interactive,
-        }) => commands::close::execute(name, force, interactive),
-        Some(Commands::Open { name, interactive }) => commands::open::execute(name, interactive),
+            keep_branch,
+        }) => commands::close::execute(name, force, interactive, keep_branch),
+        Some(Commands::Open {
+            name,
+            interactive,
+            read_only,
+            detach_others,
+        }) => commands::open::execute(name, interactive, read_only, detach_others),
         Some(Commands::Rename {
             new_name,
             worktree,
             clear,
-        }) => commands::rename::execute(new_name, worktree, clear),
-        Some(Commands::List { json, all }) => commands::list::execute(json, all),
+            allow_duplicate,
+        }) => commands::rename::execute(new_name, worktree, clear, allow_duplicate),
+        Some(Commands::List {
+            json,
+            all,
+            no_status,
+            tag,
+        }) => commands::list::execute(json, all, no_status, tag),
+        Some(Commands::Tag { name, add, remove }) => commands::tag::execute(name, add, remove),
         Some(Commands::Cleanup {
             older_than,
             force,
             all,
         }) => commands::cleanup::execute(older_than, force, all),
-        Some(Commands::Status { name }) => commands::status::execute(name),
+        Some(Commands::Status { name, json }) => commands::status::execute(name, json),
         Some(Commands::Path { name }) => commands::path::execute(name),
         Some(Commands::Completions { shell }) => {
             let mut cmd = Cli::command();
@@ -68,3 +97,58 @@ fn main() -> Result<()> {
         }
     }
 }
+
+/// Resolve the effective configuration and print each value alongside the source
+/// it was loaded from, so users can debug why a setting took effect.
+fn print_config() -> Result<()> {
+    use colored::Colorize;
+    use config::settings::MergedSettings;
+
+    // Resolve from the main repository root when inside a repo, otherwise the
+    // current directory, so discovery walks the same ancestors commands use.
+    let root = if git::is_git_repo() {
+        git::get_main_repo_root()?
+    } else {
+        std::env::current_dir()?
+    };
+
+    let (settings, provenance) = MergedSettings::load_with_provenance(&root)?;
+
+    let value_for = |field: &str| -> String {
+        match field {
+            "port_count" => settings.port_count.to_string(),
+            "port_range_start" => settings.port_range_start.to_string(),
+            "port_range_end" => settings.port_range_end.to_string(),
+            "branch_prefix" => settings.branch_prefix.clone(),
+            "auto_launch_terminal" => settings.auto_launch_terminal.to_string(),
+            "worktree_dir" => settings
+                .worktree_dir
+                .as_ref()
+                .map(|p| p.display().to_string())
+                .unwrap_or_else(|| "(default)".to_string()),
+            "terminal" => settings
+                .terminal
+                .clone()
+                .unwrap_or_else(|| "(auto-detect)".to_string()),
+            "shell" => match settings.shell.resolve() {
+                None => "system".to_string(),
+                Some((program, args)) if args.is_empty() => program,
+                Some((program, args)) => format!("{} {}", program, args.join(" ")),
+            },
+            "working_directory" => format!("{:?}", settings.working_directory),
+            _ => String::new(),
+        }
+    };
+
+    println!("{}", "Resolved configuration:".bold());
+    for (field, source) in provenance.entries() {
+        println!(
+            "  {} = {}  {}",
+            field,
+            value_for(field),
+            format!("({})", source).dimmed()
+        );
+    }
+
+    Ok(())
+}
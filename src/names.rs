@@ -1,5 +1,10 @@
 use rand::seq::SliceRandom;
 use rand::Rng;
+use std::collections::HashSet;
+
+/// Longest directory stem kept from a slugified display name before the unique
+/// suffix is appended, so names stay readable and path-length friendly.
+const MAX_SLUG_STEM: usize = 32;
 
 const ADJECTIVES: &[&str] = &[
     "swift", "happy", "cool", "brave", "bright", "calm", "clever", "eager", "fair", "fierce",
@@ -19,6 +24,26 @@ const NOUNS: &[&str] = &[
     "breeze", "frost", "mist", "cloud", "rain",
 ];
 
+/// Environment variable overriding the auto-detected project/session name.
+pub const REPO_NAME_ENV: &str = "WORKTREE_REPO_NAME";
+
+/// Resolve the project name used for session names and port-allocation keys.
+///
+/// Priority: an explicit name, then the `WORKTREE_REPO_NAME` override, then the
+/// basename of the Git repository's top level — so invoking the tool from
+/// inside any repo yields a sensible name without typing the project out.
+pub fn resolve_project_name(explicit: Option<String>) -> anyhow::Result<String> {
+    if let Some(name) = explicit.filter(|n| !n.is_empty()) {
+        return Ok(name);
+    }
+    if let Ok(name) = std::env::var(REPO_NAME_ENV) {
+        if !name.is_empty() {
+            return Ok(name);
+        }
+    }
+    crate::git::get_main_project_name()
+}
+
 /// Generate a random worktree name in format: adjective-noun-suffix
 /// Example: swift-falcon-a3b2
 pub fn generate() -> String {
@@ -27,15 +52,61 @@ pub fn generate() -> String {
     let adjective = ADJECTIVES.choose(&mut rng).unwrap();
     let noun = NOUNS.choose(&mut rng).unwrap();
 
-    // Generate a 4-character hex suffix
-    let suffix: String = (0..4)
+    format!("{}-{}-{}", adjective, noun, hex_suffix())
+}
+
+/// Generate a random name that is guaranteed not to collide with any of the
+/// `existing` worktree directory stems, regenerating until one is free.
+pub fn generate_unique(existing: &HashSet<String>) -> String {
+    loop {
+        let name = generate();
+        if !existing.contains(&name) {
+            return name;
+        }
+    }
+}
+
+/// Derive a filesystem-safe worktree directory name from a user-supplied
+/// display name: the slugified stem, truncated, with a 4-char hex suffix for
+/// uniqueness. Falls back to the random generator when the stem is empty.
+pub fn slugify(param: &str) -> String {
+    let mut stem = slugify_stem(param);
+    stem.truncate(MAX_SLUG_STEM);
+    let stem = stem.trim_matches('-');
+    if stem.is_empty() {
+        return generate();
+    }
+    format!("{}-{}", stem, hex_suffix())
+}
+
+/// A random 4-character hex string used as a uniqueness suffix.
+fn hex_suffix() -> String {
+    let mut rng = rand::thread_rng();
+    (0..4)
         .map(|_| {
             let idx = rng.gen_range(0..16);
             "0123456789abcdef".chars().nth(idx).unwrap()
         })
-        .collect();
+        .collect()
+}
 
-    format!("{}-{}-{}", adjective, noun, suffix)
+/// Collapse an arbitrary reference into a filesystem-friendly stem: lowercase,
+/// with non-alphanumeric runs reduced to single dashes and edges trimmed
+/// (`feature/new-ui` → `feature-new-ui`). The shared core of [`slugify`], also
+/// used directly when a branch reference already guarantees uniqueness.
+pub fn slugify_stem(reference: &str) -> String {
+    let mut slug = String::new();
+    let mut prev_dash = false;
+    for ch in reference.chars() {
+        if ch.is_ascii_alphanumeric() {
+            slug.push(ch.to_ascii_lowercase());
+            prev_dash = false;
+        } else if !slug.is_empty() && !prev_dash {
+            slug.push('-');
+            prev_dash = true;
+        }
+    }
+    slug.trim_matches('-').to_string()
 }
 
 #[cfg(test)]
@@ -53,10 +124,37 @@ mod tests {
     }
 
     #[test]
-    fn test_generate_unique() {
-        let name1 = generate();
-        let name2 = generate();
-        // With 56 adjectives * 56 nouns * 65536 suffixes, collision is very unlikely
-        assert_ne!(name1, name2);
+    fn test_slugify_stem() {
+        assert_eq!(slugify_stem("feature/new-ui"), "feature-new-ui");
+        assert_eq!(slugify_stem("feature/x"), "feature-x");
+        assert_eq!(slugify_stem("JIRA-123_fix"), "jira-123-fix");
+    }
+
+    #[test]
+    fn test_slugify_appends_suffix() {
+        let name = slugify("My Cool Feature!");
+        assert!(name.starts_with("my-cool-feature-"));
+        // Stem plus a 4-char hex suffix.
+        let suffix = name.rsplit('-').next().unwrap();
+        assert_eq!(suffix.len(), 4);
+        assert!(suffix.chars().all(|c| c.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn test_slugify_empty_stem_falls_back() {
+        // A param with no alphanumerics yields a random generated name.
+        let name = slugify("///");
+        let parts: Vec<&str> = name.split('-').collect();
+        assert_eq!(parts.len(), 3);
+        assert!(ADJECTIVES.contains(&parts[0]));
+    }
+
+    #[test]
+    fn test_generate_unique_avoids_existing() {
+        let mut existing = HashSet::new();
+        let taken = generate();
+        existing.insert(taken.clone());
+        let name = generate_unique(&existing);
+        assert!(!existing.contains(&name));
     }
 }
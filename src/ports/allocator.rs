@@ -42,6 +42,17 @@ impl PortAllocations {
         self.allocations.values().flatten().copied().collect()
     }
 
+    /// Get the ports allocated on a single host (`None` for the local machine).
+    /// Ports on other hosts are excluded so the same number can be reused on
+    /// different machines without colliding.
+    pub fn allocated_ports_on(&self, host: Option<&str>) -> HashSet<u16> {
+        self.allocations
+            .iter()
+            .filter(|(key, _)| key_host(key) == host)
+            .flat_map(|(_, ports)| ports.iter().copied())
+            .collect()
+    }
+
     /// Clean up stale allocations (worktrees that no longer exist)
     pub fn cleanup_stale(&mut self) -> Vec<String> {
         let mut stale_keys = Vec::new();
@@ -63,7 +74,8 @@ impl PortAllocations {
 
     /// Check if a worktree exists for the given allocation key
     fn worktree_exists(&self, key: &str) -> bool {
-        // Key format is "project/worktree" or just "worktree"
+        // Key format is "project/worktree", "host/project/worktree" (remote), or
+        // just "worktree". The worktree directory itself is always local.
         let parts: Vec<&str> = key.split('/').collect();
 
         let global_dir = match paths::global_worktrees_dir() {
@@ -71,12 +83,12 @@ impl PortAllocations {
             Err(_) => return false, // Can't determine home directory
         };
 
-        let worktree_path = if parts.len() == 2 {
+        let worktree_path = match parts.as_slice() {
             // Default path: ~/.worktree/worktrees/project/worktree
-            global_dir.join(parts[0]).join(parts[1])
-        } else {
+            [_host, project, worktree] => global_dir.join(project).join(worktree),
+            [project, worktree] => global_dir.join(project).join(worktree),
             // Single name - could be in custom directory, check global too
-            global_dir.join(key)
+            _ => global_dir.join(key),
         };
 
         let state_path = worktree_path.join("state.json");
@@ -84,12 +96,28 @@ impl PortAllocations {
     }
 }
 
-/// Allocate ports for a worktree
+/// Extract the host namespace from an allocation key, if it carries one.
+/// Remote keys are `host/project/worktree`; local keys are `project/worktree`.
+fn key_host(key: &str) -> Option<&str> {
+    let parts: Vec<&str> = key.split('/').collect();
+    if parts.len() == 3 {
+        Some(parts[0])
+    } else {
+        None
+    }
+}
+
+/// Allocate ports for a worktree.
+///
+/// `host` namespaces the search to a single machine (`None` for local), so the
+/// same port number can be reused on different hosts. The host is normally
+/// encoded in `key` as well; it is passed explicitly to keep exclusion cheap.
 pub fn allocate(
     count: u16,
     key: &str,
     range_start: u16,
     range_end: u16,
+    host: Option<&str>,
 ) -> Result<AllocationResult> {
     let mut allocations = PortAllocations::load()?;
 
@@ -104,8 +132,8 @@ pub fn allocate(
         });
     }
 
-    // Find free ports
-    let excluded = allocations.all_allocated_ports();
+    // Find free ports, excluding only those in use on the same host
+    let excluded = allocations.allocated_ports_on(host);
     let ports =
         find_consecutive_free(count, range_start, range_end, &excluded).ok_or_else(|| {
             anyhow::anyhow!(
@@ -176,6 +204,29 @@ mod tests {
         assert!(all_ports.contains(&50011));
     }
 
+    #[test]
+    fn test_allocated_ports_on_namespaces_by_host() {
+        let mut allocations = PortAllocations::default();
+        allocations
+            .allocations
+            .insert("project/local".to_string(), vec![5000, 5001]);
+        allocations
+            .allocations
+            .insert("user@box/project/remote".to_string(), vec![5000, 5002]);
+
+        // Local exclusion only sees the local allocation.
+        let local = allocations.allocated_ports_on(None);
+        assert_eq!(local.len(), 2);
+        assert!(local.contains(&5000));
+        assert!(local.contains(&5001));
+
+        // The remote host has its own namespace; 5000 does not collide.
+        let remote = allocations.allocated_ports_on(Some("user@box"));
+        assert_eq!(remote.len(), 2);
+        assert!(remote.contains(&5000));
+        assert!(remote.contains(&5002));
+    }
+
     #[test]
     fn test_port_allocations_json_roundtrip() {
         let mut allocations = PortAllocations::default();
@@ -0,0 +1,22 @@
+//! Safe construction of external commands.
+
+use std::process::Command;
+
+/// Build a [`Command`] for `program`, resolving it to an absolute path on `PATH`
+/// first.
+///
+/// On Windows a bare `Command::new("git")` will happily execute a `git.exe`
+/// sitting in the current working directory — and this tool runs commands from
+/// inside checked-out (untrusted) worktrees. Resolving through `which` pins the
+/// executable to the one on `PATH`, falling back to the bare name only when
+/// resolution fails (e.g. a shim not yet on `PATH`).
+// This is the one sanctioned place that constructs a `Command` by name; every
+// other spawn site routes through here, enforced by the `disallowed-methods`
+// clippy lint in `clippy.toml`.
+#[allow(clippy::disallowed_methods)]
+pub fn create_command(program: &str) -> Command {
+    match which::which(program) {
+        Ok(path) => Command::new(path),
+        Err(_) => Command::new(program),
+    }
+}
@@ -0,0 +1,186 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// How a matched file should be provisioned into a new worktree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProvisionMode {
+    /// Copy the file into the worktree.
+    Copy,
+    /// Symlink the worktree entry back to the main checkout.
+    Symlink,
+    /// Skip the file entirely.
+    Ignore,
+}
+
+/// Provision untracked files (like `.env`) that git worktrees don't carry over.
+///
+/// Walks the main repository root for files matching any glob in `special_paths`
+/// and, for each match, copies it, symlinks it back to the main checkout, or
+/// skips it, preserving the relative directory structure under the worktree.
+/// Returns a per-file summary (e.g. "Linked .env", "Copied config/db.json").
+pub fn provision_worktree(
+    main_root: &Path,
+    worktree_dir: &Path,
+    special_paths: &HashMap<String, ProvisionMode>,
+    exclude: &[String],
+) -> Result<Vec<String>> {
+    let mut summary = Vec::new();
+
+    if special_paths.is_empty() {
+        return Ok(summary);
+    }
+
+    for entry in WalkDir::new(main_root)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|e| {
+            // Never descend into the git metadata or our own config directory.
+            let name = e.file_name().to_string_lossy();
+            name != ".git" && name != ".worktree"
+        })
+        .filter_map(|e| e.ok())
+    {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let rel = match entry.path().strip_prefix(main_root) {
+            Ok(rel) => rel,
+            Err(_) => continue,
+        };
+        let rel_str = rel.to_string_lossy().replace('\\', "/");
+
+        // Exceptions win over any matching special path.
+        if exclude.iter().any(|g| glob_match(g, &rel_str)) {
+            continue;
+        }
+
+        // The most specific glob wins when several match.
+        let mode = special_paths
+            .iter()
+            .filter(|(glob, _)| glob_match(glob, &rel_str))
+            .max_by_key(|(glob, _)| glob_specificity(glob))
+            .map(|(_, mode)| *mode);
+
+        let Some(mode) = mode else { continue };
+        if mode == ProvisionMode::Ignore {
+            continue;
+        }
+
+        let dest = worktree_dir.join(rel);
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+
+        match mode {
+            ProvisionMode::Copy => {
+                std::fs::copy(entry.path(), &dest)
+                    .with_context(|| format!("Failed to copy {}", rel_str))?;
+                summary.push(format!("Copied {}", rel_str));
+            }
+            ProvisionMode::Symlink => {
+                symlink_file(entry.path(), &dest)
+                    .with_context(|| format!("Failed to symlink {}", rel_str))?;
+                summary.push(format!("Linked {}", rel_str));
+            }
+            ProvisionMode::Ignore => unreachable!("ignore handled above"),
+        }
+    }
+
+    Ok(summary)
+}
+
+#[cfg(unix)]
+fn symlink_file(src: &Path, dest: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(src, dest)
+}
+
+#[cfg(windows)]
+fn symlink_file(src: &Path, dest: &Path) -> std::io::Result<()> {
+    std::os::windows::fs::symlink_file(src, dest)
+}
+
+/// Specificity heuristic for choosing between overlapping globs: count the
+/// literal (non-wildcard) characters, tie-breaking on overall length.
+fn glob_specificity(glob: &str) -> (usize, usize) {
+    let literals = glob
+        .chars()
+        .filter(|c| !matches!(c, '*' | '?'))
+        .count();
+    (literals, glob.len())
+}
+
+/// Match a `/`-separated path against a glob supporting `*`, `**`, and `?`.
+/// `*` matches within a path segment, `**` matches across segments.
+pub fn glob_match(pattern: &str, path: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let t: Vec<char> = path.chars().collect();
+    match_from(&p, 0, &t, 0)
+}
+
+fn match_from(p: &[char], mut pi: usize, t: &[char], mut ti: usize) -> bool {
+    while pi < p.len() {
+        match p[pi] {
+            '*' => {
+                // `**` matches any run of characters including path separators.
+                let double = pi + 1 < p.len() && p[pi + 1] == '*';
+                let rest = if double { pi + 2 } else { pi + 1 };
+                for skip in ti..=t.len() {
+                    if match_from(p, rest, t, skip) {
+                        return true;
+                    }
+                    // A single `*` does not cross path separators.
+                    if !double && skip < t.len() && t[skip] == '/' {
+                        break;
+                    }
+                }
+                return false;
+            }
+            '?' => {
+                if ti >= t.len() || t[ti] == '/' {
+                    return false;
+                }
+                pi += 1;
+                ti += 1;
+            }
+            c => {
+                if ti >= t.len() || t[ti] != c {
+                    return false;
+                }
+                pi += 1;
+                ti += 1;
+            }
+        }
+    }
+
+    ti == t.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match_star() {
+        assert!(glob_match(".env*", ".env"));
+        assert!(glob_match(".env*", ".env.local"));
+        assert!(!glob_match(".env*", "config/.env"));
+    }
+
+    #[test]
+    fn test_glob_match_double_star() {
+        assert!(glob_match("config/secrets/**", "config/secrets/db.json"));
+        assert!(glob_match("config/secrets/**", "config/secrets/a/b.json"));
+        assert!(!glob_match("config/secrets/**", "config/other.json"));
+    }
+
+    #[test]
+    fn test_glob_specificity_prefers_literals() {
+        assert!(glob_specificity("config/db.json") > glob_specificity(".env*"));
+    }
+}
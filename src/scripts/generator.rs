@@ -2,7 +2,7 @@ use anyhow::{Context, Result};
 use std::fs::File;
 use std::io::{BufRead, BufReader, Write};
 use std::path::Path;
-use std::process::{Command, Stdio};
+use std::process::Stdio;
 
 /// Check if Claude CLI is available
 pub fn is_claude_available() -> bool {
@@ -38,7 +38,7 @@ Make scripts executable and include proper error handling."#;
 
     // Run Claude with output going to both terminal and file
     // Using a pipe to read output line by line
-    let mut child = Command::new("claude")
+    let mut child = crate::process::create_command("claude")
         .args(["--print", prompt])
         .current_dir(project_dir)
         .stdin(Stdio::inherit()) // Allow user input for permission prompts
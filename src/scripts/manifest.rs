@@ -0,0 +1,533 @@
+//! Declarative lifecycle manifest.
+//!
+//! A project may ship a `.worktree/worktree.toml` describing its lifecycle
+//! phases instead of (or alongside) the generated `setup.sh`/`run.sh`/etc.
+//! scripts. The manifest is parsed, cross-platform, and expands the same
+//! environment variables the scripts receive, so a phase can be described
+//! declaratively and run on any platform with a shell.
+//!
+//! ```toml
+//! [setup]
+//! workdir = "frontend"
+//! env = { API = "http://localhost:${PORT_1}" }
+//! commands = [
+//!     "npm install",
+//!     "cp .env.example .env",
+//! ]
+//!
+//! [run]
+//! commands = ["npm run dev -- --port ${PORT_0}"]
+//! ```
+
+use anyhow::{bail, Context, Result};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::config::state::WorktreeState;
+
+/// The four lifecycle phases, mirroring the generated scripts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Setup,
+    Run,
+    Stop,
+    Close,
+}
+
+impl Phase {
+    /// The manifest section name for this phase.
+    fn section(&self) -> &'static str {
+        match self {
+            Phase::Setup => "setup",
+            Phase::Run => "run",
+            Phase::Stop => "stop",
+            Phase::Close => "close",
+        }
+    }
+}
+
+/// A single lifecycle phase: ordered commands plus optional declared env and a
+/// working directory (relative to the worktree root).
+#[derive(Debug, Clone, Default)]
+pub struct PhaseSpec {
+    pub commands: Vec<String>,
+    pub env: Vec<(String, String)>,
+    pub workdir: Option<String>,
+}
+
+/// A parsed lifecycle manifest.
+#[derive(Debug, Clone, Default)]
+pub struct Manifest {
+    phases: HashMap<String, PhaseSpec>,
+}
+
+/// The manifest file name, relative to a worktree's `.worktree` directory.
+pub const MANIFEST_FILE: &str = "worktree.toml";
+
+impl Manifest {
+    /// Look for a manifest under `<worktree_dir>/.worktree/worktree.toml`,
+    /// returning `None` when it is absent.
+    pub fn discover(worktree_dir: &Path) -> Result<Option<Manifest>> {
+        let path = worktree_dir.join(".worktree").join(MANIFEST_FILE);
+        if !path.exists() {
+            return Ok(None);
+        }
+        Ok(Some(Manifest::load(&path)?))
+    }
+
+    /// Parse a manifest from `path`.
+    pub fn load(path: &Path) -> Result<Manifest> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read manifest {}", path.display()))?;
+        Manifest::parse(&content)
+            .with_context(|| format!("Failed to parse manifest {}", path.display()))
+    }
+
+    /// Whether the manifest declares any commands for `phase`.
+    pub fn has_phase(&self, phase: Phase) -> bool {
+        self.phases
+            .get(phase.section())
+            .is_some_and(|spec| !spec.commands.is_empty())
+    }
+
+    /// Run every command in `phase`, expanding the lifecycle environment
+    /// variables and streaming output. Returns `Ok(())` when the phase has no
+    /// commands so callers can treat a missing section as a no-op.
+    pub fn run_phase(&self, phase: Phase, state: &WorktreeState) -> Result<()> {
+        let spec = match self.phases.get(phase.section()) {
+            Some(spec) if !spec.commands.is_empty() => spec,
+            _ => return Ok(()),
+        };
+
+        let vars = expansion_vars(state);
+
+        // Declared env is itself interpolated and layered on top of the
+        // lifecycle variables the scripts receive.
+        let mut env = crate::scripts::build_env_vars(state);
+        for (key, value) in &spec.env {
+            env.insert(key.clone(), expand(value, &vars));
+        }
+
+        let workdir = match &spec.workdir {
+            Some(dir) => state.worktree_dir.join(expand(dir, &vars)),
+            None => state.worktree_dir.clone(),
+        };
+
+        for command in &spec.commands {
+            let command = expand(command, &vars);
+            println!("  $ {}", command);
+            run_command(&command, &env, &workdir)
+                .with_context(|| format!("Command failed: {}", command))?;
+        }
+
+        Ok(())
+    }
+
+    /// Parse the TOML subset we support: `[section]` headers and `key = value`
+    /// entries where a value is a quoted string, a `[ ... ]` array of strings,
+    /// or a `{ ... }` inline table. Values may span multiple lines.
+    fn parse(content: &str) -> Result<Manifest> {
+        let mut phases: HashMap<String, PhaseSpec> = HashMap::new();
+        let mut section: Option<String> = None;
+
+        let mut lines = content.lines().peekable();
+        while let Some(raw) = lines.next() {
+            let line = strip_comment(raw).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let name = name.trim().to_string();
+                phases.entry(name.clone()).or_default();
+                section = Some(name);
+                continue;
+            }
+
+            let (key, mut value) = line
+                .split_once('=')
+                .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+                .ok_or_else(|| anyhow::anyhow!("Expected `key = value`, found: {}", line))?;
+
+            // Accumulate continuation lines until brackets/braces balance.
+            while needs_continuation(&value) {
+                let next = lines
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("Unterminated value for `{}`", key))?;
+                value.push(' ');
+                value.push_str(strip_comment(next).trim());
+            }
+
+            let section = section
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("Entry `{}` before any [section]", key))?;
+            let spec = phases.entry(section.clone()).or_default();
+
+            match key.as_str() {
+                "commands" => spec.commands = parse_string_array(&value)?,
+                "command" => spec.commands.push(parse_string(&value)?),
+                "workdir" => spec.workdir = Some(parse_string(&value)?),
+                "env" => spec.env = parse_inline_table(&value)?,
+                other if other.starts_with("env.") => {
+                    spec.env
+                        .push((other["env.".len()..].to_string(), parse_string(&value)?));
+                }
+                other => bail!("Unknown manifest key `{}`", other),
+            }
+        }
+
+        Ok(Manifest { phases })
+    }
+}
+
+/// A value still has unbalanced `[`/`{` and needs more lines appended.
+fn needs_continuation(value: &str) -> bool {
+    let opens = value.matches('[').count() + value.matches('{').count();
+    let closes = value.matches(']').count() + value.matches('}').count();
+    opens > closes
+}
+
+/// Drop a trailing `#` comment, respecting quoted strings.
+fn strip_comment(line: &str) -> &str {
+    let mut in_quote = false;
+    for (i, ch) in line.char_indices() {
+        match ch {
+            '"' => in_quote = !in_quote,
+            '#' if !in_quote => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Unquote a single `"..."` string.
+fn parse_string(value: &str) -> Result<String> {
+    let value = value.trim();
+    let inner = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .ok_or_else(|| anyhow::anyhow!("Expected a quoted string, found: {}", value))?;
+    Ok(inner.to_string())
+}
+
+/// Parse a `[ "a", "b", ]` array of strings.
+fn parse_string_array(value: &str) -> Result<Vec<String>> {
+    let value = value.trim();
+    let inner = value
+        .strip_prefix('[')
+        .and_then(|v| v.strip_suffix(']'))
+        .ok_or_else(|| anyhow::anyhow!("Expected an array, found: {}", value))?;
+    split_top_level(inner)
+        .iter()
+        .map(|item| parse_string(item))
+        .collect()
+}
+
+/// Parse a `{ KEY = "v", OTHER = "w" }` inline table of strings.
+fn parse_inline_table(value: &str) -> Result<Vec<(String, String)>> {
+    let value = value.trim();
+    let inner = value
+        .strip_prefix('{')
+        .and_then(|v| v.strip_suffix('}'))
+        .ok_or_else(|| anyhow::anyhow!("Expected an inline table, found: {}", value))?;
+    split_top_level(inner)
+        .iter()
+        .map(|pair| {
+            let (k, v) = pair
+                .split_once('=')
+                .ok_or_else(|| anyhow::anyhow!("Expected `key = value` in table: {}", pair))?;
+            Ok((k.trim().to_string(), parse_string(v)?))
+        })
+        .collect()
+}
+
+/// Split a comma-separated list, honouring quoted strings and ignoring a
+/// trailing comma.
+fn split_top_level(inner: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut current = String::new();
+    let mut in_quote = false;
+    for ch in inner.chars() {
+        match ch {
+            '"' => {
+                in_quote = !in_quote;
+                current.push(ch);
+            }
+            ',' if !in_quote => {
+                if !current.trim().is_empty() {
+                    items.push(current.trim().to_string());
+                }
+                current.clear();
+            }
+            _ => current.push(ch),
+        }
+    }
+    if !current.trim().is_empty() {
+        items.push(current.trim().to_string());
+    }
+    items
+}
+
+/// Build the interpolation table: the lifecycle variables plus `WORKTREE_`-less
+/// aliases so a manifest can write the shorter `${PORT_0}`, `${NAME}`, etc.
+fn expansion_vars(state: &WorktreeState) -> HashMap<String, String> {
+    let mut vars = crate::scripts::build_env_vars(state);
+    for (key, value) in vars.clone() {
+        if let Some(alias) = key.strip_prefix("WORKTREE_") {
+            vars.entry(alias.to_string()).or_insert(value);
+        }
+    }
+    vars
+}
+
+/// Replace every `${NAME}` occurrence with its value, leaving unknown names in
+/// place so a genuine shell reference survives to the spawned command.
+fn expand(input: &str, vars: &HashMap<String, String>) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find("${") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        match after.find('}') {
+            Some(end) => {
+                let name = &after[..end];
+                match vars.get(name) {
+                    Some(value) => out.push_str(value),
+                    None => {
+                        out.push_str("${");
+                        out.push_str(name);
+                        out.push('}');
+                    }
+                }
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push_str(&rest[start..]);
+                rest = "";
+                break;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Run a single command through the platform shell, streaming its output.
+#[cfg(unix)]
+fn run_command(command: &str, env: &HashMap<String, String>, workdir: &Path) -> Result<()> {
+    run_shell("bash", &["-c", command], env, workdir)
+}
+
+#[cfg(windows)]
+fn run_command(command: &str, env: &HashMap<String, String>, workdir: &Path) -> Result<()> {
+    run_shell("cmd", &["/C", command], env, workdir)
+}
+
+fn run_shell(
+    program: &str,
+    args: &[&str],
+    env: &HashMap<String, String>,
+    workdir: &Path,
+) -> Result<()> {
+    let status = crate::process::create_command(program)
+        .args(args)
+        .envs(env)
+        .current_dir(workdir)
+        .status()
+        .with_context(|| format!("Failed to spawn {}", program))?;
+
+    if !status.success() {
+        bail!("exited with status: {}", status.code().unwrap_or(-1));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands_array_and_workdir() {
+        let manifest = Manifest::parse(
+            r#"
+            [setup]
+            workdir = "frontend"
+            commands = ["npm install", "cp .env.example .env"]
+            "#,
+        )
+        .unwrap();
+
+        let spec = manifest.phases.get("setup").unwrap();
+        assert_eq!(spec.workdir.as_deref(), Some("frontend"));
+        assert_eq!(
+            spec.commands,
+            vec!["npm install".to_string(), "cp .env.example .env".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_parse_single_command_appends() {
+        let manifest = Manifest::parse(
+            r#"
+            [run]
+            command = "npm run dev"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            manifest.phases.get("run").unwrap().commands,
+            vec!["npm run dev".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_parse_inline_table_env() {
+        let manifest = Manifest::parse(
+            r#"
+            [setup]
+            env = { API = "http://localhost:${PORT_1}", MODE = "dev" }
+            commands = ["true"]
+            "#,
+        )
+        .unwrap();
+
+        let spec = manifest.phases.get("setup").unwrap();
+        assert_eq!(
+            spec.env,
+            vec![
+                ("API".to_string(), "http://localhost:${PORT_1}".to_string()),
+                ("MODE".to_string(), "dev".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_dotted_env_key() {
+        let manifest = Manifest::parse(
+            r#"
+            [run]
+            env.API_URL = "http://localhost:8080"
+            commands = ["true"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            manifest.phases.get("run").unwrap().env,
+            vec![("API_URL".to_string(), "http://localhost:8080".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_parse_quoted_string_with_special_chars() {
+        // `#`, `,` and brackets inside a quoted string must not be treated as
+        // a comment delimiter, item separator, or array/table boundary.
+        let manifest = Manifest::parse(
+            r#"
+            [run]
+            commands = ["echo 'a, b # c [d] {e}'"] # trailing comment
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            manifest.phases.get("run").unwrap().commands,
+            vec!["echo 'a, b # c [d] {e}'".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_parse_multiline_array() {
+        let manifest = Manifest::parse(
+            r#"
+            [setup]
+            commands = [
+                "npm install",
+                "npm run build",
+            ]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            manifest.phases.get("setup").unwrap().commands,
+            vec!["npm install".to_string(), "npm run build".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_parse_multiline_inline_table() {
+        let manifest = Manifest::parse(
+            r#"
+            [setup]
+            env = {
+                API = "http://localhost",
+                MODE = "dev",
+            }
+            commands = ["true"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            manifest.phases.get("setup").unwrap().env,
+            vec![
+                ("API".to_string(), "http://localhost".to_string()),
+                ("MODE".to_string(), "dev".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown_key_errors() {
+        let err = Manifest::parse(
+            r#"
+            [setup]
+            bogus = "value"
+            "#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Unknown manifest key"));
+    }
+
+    #[test]
+    fn test_parse_entry_before_section_errors() {
+        let err = Manifest::parse(r#"commands = ["true"]"#).unwrap_err();
+        assert!(err.to_string().contains("before any [section]"));
+    }
+
+    #[test]
+    fn test_has_phase_missing_section() {
+        let manifest = Manifest::parse(
+            r#"
+            [setup]
+            commands = ["true"]
+            "#,
+        )
+        .unwrap();
+
+        assert!(manifest.has_phase(Phase::Setup));
+        assert!(!manifest.has_phase(Phase::Run));
+    }
+
+    #[test]
+    fn test_expand_replaces_known_vars() {
+        let mut vars = HashMap::new();
+        vars.insert("NAME".to_string(), "my-worktree".to_string());
+        assert_eq!(expand("hello ${NAME}!", &vars), "hello my-worktree!");
+    }
+
+    #[test]
+    fn test_expand_leaves_unknown_placeholder() {
+        let vars = HashMap::new();
+        assert_eq!(expand("echo ${UNKNOWN}", &vars), "echo ${UNKNOWN}");
+    }
+
+    #[test]
+    fn test_expand_unterminated_placeholder_passthrough() {
+        let vars = HashMap::new();
+        assert_eq!(expand("echo ${NAME", &vars), "echo ${NAME");
+    }
+}
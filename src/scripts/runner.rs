@@ -1,9 +1,19 @@
 use anyhow::{bail, Context, Result};
 use std::collections::HashMap;
-use std::path::Path;
-use std::process::Command;
+use std::path::{Path, PathBuf};
 
 use crate::config::state::WorktreeState;
+use crate::process::create_command;
+
+/// Per-worktree tmux configuration sourced into the session on open.
+const TMUX_CONF: &str = "session.tmux";
+
+/// Resolve the optional per-worktree tmux config file (`.worktree/session.tmux`),
+/// discovered alongside the lifecycle scripts. Returns `None` when absent.
+pub fn tmux_conf_path(worktree_dir: &Path) -> Option<PathBuf> {
+    let path = worktree_dir.join(".worktree").join(TMUX_CONF);
+    path.exists().then_some(path)
+}
 
 /// Build environment variables for lifecycle scripts
 pub fn build_env_vars(state: &WorktreeState) -> HashMap<String, String> {
@@ -32,6 +42,15 @@ pub fn build_env_vars(state: &WorktreeState) -> HashMap<String, String> {
         env.insert("WORKTREE_PARAM".to_string(), param.clone());
     }
 
+    // Expose the per-worktree tmux config path when present, so scripts can
+    // reference the same file the terminal integration sources.
+    if let Some(conf) = tmux_conf_path(&state.worktree_dir) {
+        env.insert(
+            "WORKTREE_TMUX_CONF".to_string(),
+            conf.to_string_lossy().to_string(),
+        );
+    }
+
     // Add port environment variables
     for (i, port) in state.ports.iter().enumerate() {
         env.insert(format!("WORKTREE_PORT_{}", i), port.to_string());
@@ -61,7 +80,7 @@ pub fn execute_script(script: &Path, env: &HashMap<String, String>) -> Result<()
         }
     }
 
-    let status = Command::new("bash")
+    let status = create_command("bash")
         .arg(script)
         .envs(env)
         .current_dir(
@@ -80,13 +99,57 @@ pub fn execute_script(script: &Path, env: &HashMap<String, String>) -> Result<()
     Ok(())
 }
 
+/// Spawn a lifecycle script as a long-lived background process and record it on
+/// `state` so it can be supervised and stopped on close. On Unix the child is
+/// placed in its own process group (the pid doubles as the group id) so any
+/// dev servers it starts are reaped together; on other platforms only the pid
+/// is tracked. Returns the spawned process id.
+pub fn spawn_background(
+    script: &Path,
+    env: &HashMap<String, String>,
+    state: &mut WorktreeState,
+    label: impl Into<String>,
+) -> Result<u32> {
+    if !script.exists() {
+        bail!("Script not found: {}", script.display());
+    }
+
+    let working_dir = script
+        .parent()
+        .and_then(|p| p.parent())
+        .unwrap_or(Path::new("."))
+        .to_path_buf();
+
+    let mut command = create_command("bash");
+    command.arg(script).envs(env).current_dir(&working_dir);
+
+    // Start the child in a fresh process group so the whole tree can be
+    // signalled by group id at close time.
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+
+    let child = command
+        .spawn()
+        .with_context(|| format!("Failed to spawn {}", script.display()))?;
+    let pid = child.id();
+
+    // On Unix the new group's id equals the leader's pid.
+    let pgid = if cfg!(unix) { Some(pid) } else { None };
+    state.record_process(pid, pgid, label)?;
+
+    Ok(pid)
+}
+
 /// Execute a lifecycle script, ignoring errors (for cleanup)
 pub fn execute_script_ignore_errors(script: &Path, env: &HashMap<String, String>) -> bool {
     if !script.exists() {
         return false;
     }
 
-    Command::new("bash")
+    create_command("bash")
         .arg(script)
         .envs(env)
         .current_dir(
@@ -1,6 +1,5 @@
 use anyhow::{Context, Result};
 use std::path::Path;
-use std::process::Command;
 
 /// Escape a string for safe use in shell commands
 /// This handles single quotes by ending the quoted string, adding an escaped quote, and resuming
@@ -9,6 +8,74 @@ fn shell_escape(s: &str) -> String {
     format!("'{}'", s.replace('\'', "'\\''"))
 }
 
+/// Escape a string for embedding inside an AppleScript double-quoted literal.
+fn applescript_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// A resolved shell invocation used when launching a terminal. `program` is
+/// `None` for the system shell (`$SHELL`).
+#[derive(Debug, Clone, Default)]
+pub struct ShellCommand {
+    pub program: Option<String>,
+    pub arguments: Vec<String>,
+}
+
+impl ShellCommand {
+    /// Build a launch command from the resolved shell setting.
+    pub fn from_config(shell: &crate::config::settings::ShellConfig) -> Self {
+        match shell.resolve() {
+            None => Self::default(),
+            Some((program, arguments)) => Self {
+                program: Some(program),
+                arguments,
+            },
+        }
+    }
+
+    /// The explicit program and its arguments, or `None` for the system shell.
+    fn program_and_args(&self) -> Option<(&str, &[String])> {
+        self.program
+            .as_deref()
+            .map(|program| (program, self.arguments.as_slice()))
+    }
+
+    /// The `exec <shell> [args]` clause of a launch command line.
+    fn exec_clause(&self) -> String {
+        match self.program_and_args() {
+            None => "exec \"$SHELL\"".to_string(),
+            Some((program, args)) => {
+                let mut parts = vec![format!("exec {}", shell_escape(program))];
+                parts.extend(args.iter().map(|a| shell_escape(a)));
+                parts.join(" ")
+            }
+        }
+    }
+
+    /// A `cd <dir> && exec <shell>` command line honoring the configured shell.
+    pub fn login_command(&self, dir: &str) -> String {
+        format!("cd {} && {}", shell_escape(dir), self.exec_clause())
+    }
+
+    /// A command line that SSHes into `remote` and opens a login shell in the
+    /// remote directory. Used when a worktree lives on a remote dev box.
+    pub fn remote_login_command(&self, remote: &RemoteHost, dir: &str) -> String {
+        // Build the remote-side command: cd <dir> && exec <shell>. The remote
+        // shell re-parses it, so it is escaped as a single argument to ssh.
+        let remote_cmd = self.login_command(dir);
+        let mut parts = vec!["ssh".to_string(), "-t".to_string()];
+        if let Some(port) = remote.port {
+            parts.push("-p".to_string());
+            parts.push(port.to_string());
+        }
+        parts.push(shell_escape(&remote.host));
+        parts.push(shell_escape(&remote_cmd));
+        parts.join(" ")
+    }
+}
+
+use crate::config::state::RemoteHost;
+
 /// Supported terminal emulators
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[allow(clippy::enum_variant_names)]
@@ -135,32 +202,50 @@ pub fn detect_terminal() -> Option<Terminal> {
     None
 }
 
-/// Launch a new terminal window in the specified directory
+/// Launch a new terminal window in the specified directory, running the
+/// configured shell.
 /// Note: For Tmux, use `launch_tmux_session` instead as it requires additional context
-pub fn launch(terminal: &Terminal, dir: &Path) -> Result<()> {
+pub fn launch(
+    terminal: &Terminal,
+    dir: &Path,
+    shell: &ShellCommand,
+    remote: Option<&RemoteHost>,
+) -> Result<()> {
     let dir_str = dir.to_str().context("Invalid directory path")?;
 
+    // When the worktree lives on a remote host, open the local terminal but run
+    // an ssh login shell in the remote directory.
+    if let Some(remote) = remote {
+        let remote_dir = remote
+            .path
+            .as_ref()
+            .map(|p| p.to_string_lossy().into_owned())
+            .unwrap_or_else(|| dir_str.to_string());
+        let command = shell.remote_login_command(remote, &remote_dir);
+        return launch_command(terminal, &command);
+    }
+
     match terminal {
         Terminal::Tmux => {
             anyhow::bail!(
                 "Use launch_tmux_session for tmux, which requires project and worktree names"
             )
         }
-        Terminal::AppleTerminal => launch_apple_terminal(dir_str),
-        Terminal::ITerm2 => launch_iterm2(dir_str),
-        Terminal::Warp => launch_warp(dir_str),
-        Terminal::Ghostty => launch_ghostty(dir_str),
+        Terminal::AppleTerminal => launch_apple_terminal(dir_str, shell),
+        Terminal::ITerm2 => launch_iterm2(dir_str, shell),
+        Terminal::Warp => launch_warp(dir_str, shell),
+        Terminal::Ghostty => launch_ghostty(dir_str, shell),
         Terminal::VSCode => launch_vscode(dir_str),
         #[cfg(target_os = "linux")]
-        Terminal::GnomeTerminal => launch_gnome_terminal(dir_str),
+        Terminal::GnomeTerminal => launch_gnome_terminal(dir_str, shell),
         #[cfg(target_os = "linux")]
-        Terminal::Konsole => launch_konsole(dir_str),
+        Terminal::Konsole => launch_konsole(dir_str, shell),
         #[cfg(target_os = "linux")]
-        Terminal::Xfce4Terminal => launch_xfce4_terminal(dir_str),
+        Terminal::Xfce4Terminal => launch_xfce4_terminal(dir_str, shell),
         #[cfg(target_os = "linux")]
-        Terminal::Kitty => launch_kitty(dir_str),
+        Terminal::Kitty => launch_kitty(dir_str, shell),
         #[cfg(target_os = "linux")]
-        Terminal::Alacritty => launch_alacritty(dir_str),
+        Terminal::Alacritty => launch_alacritty(dir_str, shell),
     }
 }
 
@@ -169,19 +254,61 @@ pub fn tmux_session_name(project_name: &str, worktree_name: &str) -> String {
     format!("{}-{}", project_name, worktree_name)
 }
 
+/// Options controlling how an existing tmux session is attached.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AttachOptions {
+    /// Attach read-only (`attach-session -r`): keystrokes are not forwarded.
+    pub read_only: bool,
+    /// Pull the session to this client and detach any others (`-d`).
+    pub detach_others: bool,
+}
+
+impl AttachOptions {
+    /// The extra `attach-session` flags implied by these options.
+    fn attach_flags(&self) -> Vec<&'static str> {
+        let mut flags = Vec::new();
+        if self.read_only {
+            flags.push("-r");
+        }
+        if self.detach_others {
+            flags.push("-d");
+        }
+        flags
+    }
+}
+
 /// Check if we're currently inside a tmux session
 fn is_inside_tmux() -> bool {
     std::env::var("TMUX").is_ok()
 }
 
-/// Launch a new tmux session for a worktree
-pub fn launch_tmux_session(project_name: &str, worktree_name: &str, dir: &Path) -> Result<()> {
-    let dir_str = dir.to_str().context("Invalid directory path")?;
+/// Launch a new tmux session for a worktree, running the configured shell
+pub fn launch_tmux_session(
+    project_name: &str,
+    worktree_name: &str,
+    dir: &Path,
+    shell: &ShellCommand,
+    remote: Option<&RemoteHost>,
+    attach: AttachOptions,
+    tmux_conf: Option<&Path>,
+) -> Result<()> {
     let session_name = tmux_session_name(project_name, worktree_name);
+
+    // Drive tmux on the remote host when configured, otherwise locally.
+    if let Some(remote) = remote {
+        let remote_dir = remote
+            .path
+            .as_ref()
+            .map(|p| p.to_string_lossy().into_owned())
+            .unwrap_or_else(|| dir.to_string_lossy().into_owned());
+        return launch_remote_tmux_session(remote, &session_name, &remote_dir, shell, attach);
+    }
+
+    let dir_str = dir.to_str().context("Invalid directory path")?;
     let inside_tmux = is_inside_tmux();
 
     // Check if session already exists
-    let session_exists = Command::new("tmux")
+    let session_exists = crate::process::create_command("tmux")
         .args(["has-session", "-t", &session_name])
         .output()
         .map(|o| o.status.success())
@@ -189,23 +316,46 @@ pub fn launch_tmux_session(project_name: &str, worktree_name: &str, dir: &Path)
 
     if !session_exists {
         // Create new session (always detached first)
-        Command::new("tmux")
-            .args(["new-session", "-d", "-s", &session_name, "-c", dir_str])
+        let mut command = crate::process::create_command("tmux");
+        command.args(["new-session", "-d", "-s", &session_name, "-c", dir_str]);
+        // Start the configured shell as the session's first command; tmux uses
+        // its default shell when none is given.
+        if let Some((program, args)) = shell.program_and_args() {
+            command.arg(program).args(args);
+        }
+        command
             .output()
             .context("Failed to create tmux session")?;
+
+        // Source the per-worktree tmux config into the freshly created session
+        // so custom layouts, status lines, or splits apply only here.
+        if let Some(conf) = tmux_conf {
+            if let Some(conf_str) = conf.to_str() {
+                let _ = crate::process::create_command("tmux")
+                    .args(["source-file", "-t", &session_name, conf_str])
+                    .output();
+            }
+        }
     }
 
     // Switch to or attach to the session
     if inside_tmux {
-        // Already in tmux, switch to the session
-        Command::new("tmux")
-            .args(["switch-client", "-t", &session_name])
+        // Already in tmux, switch to the session. `-r` toggles read-only and
+        // `-d` is not meaningful for switch-client, so only read-only applies.
+        let mut command = crate::process::create_command("tmux");
+        command.args(["switch-client", "-t", &session_name]);
+        if attach.read_only {
+            command.arg("-r");
+        }
+        command
             .status()
             .context("Failed to switch to tmux session")?;
     } else {
-        // Not in tmux, attach to the session
-        Command::new("tmux")
-            .args(["attach-session", "-t", &session_name])
+        // Not in tmux, attach to the session with the requested flags.
+        let mut command = crate::process::create_command("tmux");
+        command.args(["attach-session", "-t", &session_name]);
+        command.args(attach.attach_flags());
+        command
             .status()
             .context("Failed to attach to tmux session")?;
     }
@@ -213,19 +363,77 @@ pub fn launch_tmux_session(project_name: &str, worktree_name: &str, dir: &Path)
     Ok(())
 }
 
+/// Create (if needed) and attach to a tmux session on a remote host over ssh.
+fn launch_remote_tmux_session(
+    remote: &RemoteHost,
+    session_name: &str,
+    remote_dir: &str,
+    shell: &ShellCommand,
+    attach: AttachOptions,
+) -> Result<()> {
+    let ssh_args = |extra: &[&str]| -> Vec<String> {
+        let mut args = vec!["-t".to_string()];
+        if let Some(port) = remote.port {
+            args.push("-p".to_string());
+            args.push(port.to_string());
+        }
+        args.push(remote.host.clone());
+        args.extend(extra.iter().map(|s| s.to_string()));
+        args
+    };
+
+    // Create the session detached if it doesn't already exist on the host.
+    let mut new_session = vec![
+        "tmux".to_string(),
+        "new-session".to_string(),
+        "-d".to_string(),
+        "-s".to_string(),
+        session_name.to_string(),
+        "-c".to_string(),
+        remote_dir.to_string(),
+    ];
+    if let Some((program, args)) = shell.program_and_args() {
+        new_session.push(program.to_string());
+        new_session.extend(args.iter().cloned());
+    }
+    let new_session_cmd = format!(
+        "tmux has-session -t {session} 2>/dev/null || {create}",
+        session = shell_escape(session_name),
+        create = new_session
+            .iter()
+            .map(|a| shell_escape(a))
+            .collect::<Vec<_>>()
+            .join(" "),
+    );
+    crate::process::create_command("ssh")
+        .args(ssh_args(&["bash", "-c", &new_session_cmd]))
+        .output()
+        .context("Failed to create remote tmux session")?;
+
+    // Attach in the current terminal over ssh, with the requested flags.
+    let mut attach_cmd = vec!["tmux", "attach-session", "-t", session_name];
+    attach_cmd.extend(attach.attach_flags());
+    crate::process::create_command("ssh")
+        .args(ssh_args(&attach_cmd))
+        .status()
+        .context("Failed to attach to remote tmux session")?;
+
+    Ok(())
+}
+
 /// Kill a tmux session for a worktree
 pub fn kill_tmux_session(project_name: &str, worktree_name: &str) -> Result<bool> {
     let session_name = tmux_session_name(project_name, worktree_name);
 
     // Check if session exists
-    let check = Command::new("tmux")
+    let check = crate::process::create_command("tmux")
         .args(["has-session", "-t", &session_name])
         .output();
 
     if let Ok(output) = check {
         if output.status.success() {
             // Session exists, kill it
-            let result = Command::new("tmux")
+            let result = crate::process::create_command("tmux")
                 .args(["kill-session", "-t", &session_name])
                 .output()
                 .context("Failed to kill tmux session")?;
@@ -238,17 +446,118 @@ pub fn kill_tmux_session(project_name: &str, worktree_name: &str) -> Result<bool
     Ok(false)
 }
 
-fn launch_apple_terminal(dir: &str) -> Result<()> {
-    let escaped_dir = shell_escape(dir);
+/// Open `terminal` running an arbitrary shell command line. Used for remote
+/// (ssh) launches where the command is a full `ssh -t host '...'` invocation.
+fn launch_command(terminal: &Terminal, command: &str) -> Result<()> {
+    match terminal {
+        Terminal::Tmux => anyhow::bail!(
+            "Use launch_tmux_session for tmux, which requires project and worktree names"
+        ),
+        Terminal::AppleTerminal => {
+            let script = format!(
+                r#"tell application "Terminal"
+            do script "{}"
+            activate
+        end tell"#,
+                applescript_escape(command)
+            );
+            crate::process::create_command("osascript")
+                .args(["-e", &script])
+                .output()
+                .context("Failed to launch Terminal.app")?;
+        }
+        Terminal::ITerm2 => {
+            let script = format!(
+                r#"tell application "iTerm"
+            create window with default profile
+            tell current session of current window
+                write text "{}"
+            end tell
+            activate
+        end tell"#,
+                applescript_escape(command)
+            );
+            crate::process::create_command("osascript")
+                .args(["-e", &script])
+                .output()
+                .context("Failed to launch iTerm2")?;
+        }
+        Terminal::Warp => {
+            let script = format!(
+                r#"tell application "Warp"
+            do script "{}"
+            activate
+        end tell"#,
+                applescript_escape(command)
+            );
+            crate::process::create_command("osascript")
+                .args(["-e", &script])
+                .output()
+                .context("Failed to launch Warp")?;
+        }
+        Terminal::Ghostty => {
+            crate::process::create_command("ghostty")
+                .args(["-e", command])
+                .spawn()
+                .context("Failed to launch Ghostty")?;
+        }
+        Terminal::VSCode => {
+            anyhow::bail!("VS Code does not support launching remote worktrees via ssh")
+        }
+        #[cfg(target_os = "linux")]
+        Terminal::GnomeTerminal => {
+            crate::env::prepare_command("gnome-terminal")
+                .args(["--tab", "--", "bash", "-c", command])
+                .spawn()
+                .context("Failed to launch GNOME Terminal")?;
+        }
+        #[cfg(target_os = "linux")]
+        Terminal::Konsole => {
+            crate::env::prepare_command("konsole")
+                .args(["--new-tab", "-e", "bash", "-c", command])
+                .spawn()
+                .context("Failed to launch Konsole")?;
+        }
+        #[cfg(target_os = "linux")]
+        Terminal::Xfce4Terminal => {
+            crate::env::prepare_command("xfce4-terminal")
+                .arg("--tab")
+                .arg("--command")
+                .arg(format!("bash -c {}", shell_escape(command)))
+                .spawn()
+                .context("Failed to launch Xfce Terminal")?;
+        }
+        #[cfg(target_os = "linux")]
+        Terminal::Kitty => {
+            crate::env::prepare_command("kitty")
+                .args(["bash", "-c", command])
+                .spawn()
+                .context("Failed to launch Kitty")?;
+        }
+        #[cfg(target_os = "linux")]
+        Terminal::Alacritty => {
+            crate::env::prepare_command("alacritty")
+                .args(["-e", "bash", "-c", command])
+                .spawn()
+                .context("Failed to launch Alacritty")?;
+        }
+    }
+
+    Ok(())
+}
+
+fn launch_apple_terminal(dir: &str, shell: &ShellCommand) -> Result<()> {
+    // `do script` runs a command in a new window; escape it for AppleScript.
+    let command = applescript_escape(&shell.login_command(dir));
     let script = format!(
         r#"tell application "Terminal"
-            do script "cd {}"
+            do script "{}"
             activate
         end tell"#,
-        escaped_dir
+        command
     );
 
-    Command::new("osascript")
+    crate::process::create_command("osascript")
         .args(["-e", &script])
         .output()
         .context("Failed to launch Terminal.app")?;
@@ -256,20 +565,20 @@ fn launch_apple_terminal(dir: &str) -> Result<()> {
     Ok(())
 }
 
-fn launch_iterm2(dir: &str) -> Result<()> {
-    let escaped_dir = shell_escape(dir);
+fn launch_iterm2(dir: &str, shell: &ShellCommand) -> Result<()> {
+    let command = applescript_escape(&shell.login_command(dir));
     let script = format!(
         r#"tell application "iTerm"
             create window with default profile
             tell current session of current window
-                write text "cd {}"
+                write text "{}"
             end tell
             activate
         end tell"#,
-        escaped_dir
+        command
     );
 
-    Command::new("osascript")
+    crate::process::create_command("osascript")
         .args(["-e", &script])
         .output()
         .context("Failed to launch iTerm2")?;
@@ -277,17 +586,17 @@ fn launch_iterm2(dir: &str) -> Result<()> {
     Ok(())
 }
 
-fn launch_warp(dir: &str) -> Result<()> {
-    let escaped_dir = shell_escape(dir);
+fn launch_warp(dir: &str, shell: &ShellCommand) -> Result<()> {
+    let command = applescript_escape(&shell.login_command(dir));
     let script = format!(
         r#"tell application "Warp"
-            do script "cd {}"
+            do script "{}"
             activate
         end tell"#,
-        escaped_dir
+        command
     );
 
-    Command::new("osascript")
+    crate::process::create_command("osascript")
         .args(["-e", &script])
         .output()
         .context("Failed to launch Warp")?;
@@ -295,10 +604,9 @@ fn launch_warp(dir: &str) -> Result<()> {
     Ok(())
 }
 
-fn launch_ghostty(dir: &str) -> Result<()> {
-    let escaped_dir = shell_escape(dir);
-    Command::new("ghostty")
-        .args(["-e", &format!("cd {} && $SHELL", escaped_dir)])
+fn launch_ghostty(dir: &str, shell: &ShellCommand) -> Result<()> {
+    crate::process::create_command("ghostty")
+        .args(["-e", &shell.login_command(dir)])
         .spawn()
         .context("Failed to launch Ghostty")?;
 
@@ -306,7 +614,7 @@ fn launch_ghostty(dir: &str) -> Result<()> {
 }
 
 fn launch_vscode(dir: &str) -> Result<()> {
-    Command::new("code")
+    crate::process::create_command("code")
         .args([dir])
         .spawn()
         .context("Failed to launch VS Code")?;
@@ -315,51 +623,64 @@ fn launch_vscode(dir: &str) -> Result<()> {
 }
 
 #[cfg(target_os = "linux")]
-fn launch_gnome_terminal(dir: &str) -> Result<()> {
-    Command::new("gnome-terminal")
-        .args(["--tab", "--working-directory", dir])
-        .spawn()
-        .context("Failed to launch GNOME Terminal")?;
+fn launch_gnome_terminal(dir: &str, shell: &ShellCommand) -> Result<()> {
+    let mut command = crate::env::prepare_command("gnome-terminal");
+    command.args(["--tab", "--working-directory", dir]);
+    if let Some((program, args)) = shell.program_and_args() {
+        command.arg("--").arg(program).args(args);
+    }
+    command.spawn().context("Failed to launch GNOME Terminal")?;
 
     Ok(())
 }
 
 #[cfg(target_os = "linux")]
-fn launch_konsole(dir: &str) -> Result<()> {
-    Command::new("konsole")
-        .args(["--new-tab", "--workdir", dir])
-        .spawn()
-        .context("Failed to launch Konsole")?;
+fn launch_konsole(dir: &str, shell: &ShellCommand) -> Result<()> {
+    let mut command = crate::env::prepare_command("konsole");
+    command.args(["--new-tab", "--workdir", dir]);
+    if let Some((program, args)) = shell.program_and_args() {
+        command.arg("-e").arg(program).args(args);
+    }
+    command.spawn().context("Failed to launch Konsole")?;
 
     Ok(())
 }
 
 #[cfg(target_os = "linux")]
-fn launch_xfce4_terminal(dir: &str) -> Result<()> {
-    Command::new("xfce4-terminal")
-        .args(["--tab", "--working-directory", dir])
-        .spawn()
-        .context("Failed to launch Xfce Terminal")?;
+fn launch_xfce4_terminal(dir: &str, shell: &ShellCommand) -> Result<()> {
+    let mut command = crate::env::prepare_command("xfce4-terminal");
+    command.args(["--tab", "--working-directory", dir]);
+    if let Some((program, args)) = shell.program_and_args() {
+        // xfce4-terminal takes the command as a single string.
+        let mut invocation = vec![shell_escape(program)];
+        invocation.extend(args.iter().map(|a| shell_escape(a)));
+        command.arg("--command").arg(invocation.join(" "));
+    }
+    command.spawn().context("Failed to launch Xfce Terminal")?;
 
     Ok(())
 }
 
 #[cfg(target_os = "linux")]
-fn launch_kitty(dir: &str) -> Result<()> {
-    Command::new("kitty")
-        .args(["--directory", dir])
-        .spawn()
-        .context("Failed to launch Kitty")?;
+fn launch_kitty(dir: &str, shell: &ShellCommand) -> Result<()> {
+    let mut command = crate::env::prepare_command("kitty");
+    command.args(["--directory", dir]);
+    if let Some((program, args)) = shell.program_and_args() {
+        command.arg(program).args(args);
+    }
+    command.spawn().context("Failed to launch Kitty")?;
 
     Ok(())
 }
 
 #[cfg(target_os = "linux")]
-fn launch_alacritty(dir: &str) -> Result<()> {
-    Command::new("alacritty")
-        .args(["--working-directory", dir])
-        .spawn()
-        .context("Failed to launch Alacritty")?;
+fn launch_alacritty(dir: &str, shell: &ShellCommand) -> Result<()> {
+    let mut command = crate::env::prepare_command("alacritty");
+    command.args(["--working-directory", dir]);
+    if let Some((program, args)) = shell.program_and_args() {
+        command.arg("-e").arg(program).args(args);
+    }
+    command.spawn().context("Failed to launch Alacritty")?;
 
     Ok(())
 }